@@ -0,0 +1,133 @@
+//!
+//! Text annotations anchored at data coordinates
+//!
+//! An annotation isn't given a legend entry. Its name is written next to the point it annotates.
+//!
+use super::*;
+
+///
+/// How an annotation is drawn.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct AnnotationStyle {
+    pub(crate) offset: [f64; 2],
+    pub(crate) arrow: bool,
+    pub(crate) highlight: bool,
+}
+
+///
+/// Text placed at a data coordinate. Create one using [`SinglePlotBuilder::annotate`].
+///
+pub struct Annotation<X, Y, D> {
+    point: Option<(X, Y)>,
+    name: D,
+    style: AnnotationStyle,
+    in_area: bool,
+    done: bool,
+}
+
+impl<D: Display> SinglePlotBuilder<D> {
+    ///
+    /// Write the name of this plot at the specified data coordinate.
+    /// The text belongs to the `poloto_annotation` and `poloto_annotation_text` css classes.
+    ///
+    pub fn annotate<X: PlotNum, Y: PlotNum>(self, x: X, y: Y) -> Annotation<X, Y, D> {
+        Annotation {
+            point: Some((x, y)),
+            name: self.label,
+            style: AnnotationStyle {
+                offset: [0.0, 0.0],
+                arrow: false,
+                highlight: false,
+            },
+            in_area: false,
+            done: false,
+        }
+    }
+}
+
+impl<X: PlotNum, Y: PlotNum, D: Display> Annotation<X, Y, D> {
+    ///
+    /// Move the text away from the point by the specified number of pixels.
+    /// Positive values move the text right and up.
+    ///
+    pub fn offset(mut self, offset: [f64; 2]) -> Self {
+        self.style.offset = offset;
+        self
+    }
+
+    ///
+    /// Draw an arrow from the text to the point. Only visible if the text is [offset](Self::offset).
+    /// The arrow belongs to the `poloto_annotation` and `poloto_annotation_arrow` css classes.
+    ///
+    pub fn arrow(mut self) -> Self {
+        self.style.arrow = true;
+        self
+    }
+
+    ///
+    /// Draw a circle around the point.
+    /// The circle belongs to the `poloto_annotation` and `poloto_annotation_highlight` css classes.
+    ///
+    pub fn highlight(mut self) -> Self {
+        self.style.highlight = true;
+        self
+    }
+
+    ///
+    /// Make sure the point is within view. By default, the point does not affect the bounds of the plot.
+    ///
+    pub fn in_area(mut self) -> Self {
+        self.in_area = true;
+        self
+    }
+}
+
+impl<X: PlotNum, Y: PlotNum, D: Display> PlotIterator for Annotation<X, Y, D> {
+    type X = X;
+    type Y = Y;
+
+    fn increase_area(&mut self, area: &mut Area<X, Y>) {
+        if let (true, Some((x, y))) = (self.in_area, &self.point) {
+            area.grow(Some(x), Some(y));
+        }
+    }
+
+    fn next_typ(&mut self) -> Option<PlotMetaType> {
+        if !self.done {
+            Some(PlotMetaType::Plot(PlotType::Annotation))
+        } else {
+            None
+        }
+    }
+
+    fn next_plot_point(&mut self) -> PlotResult<(X, Y)> {
+        if let Some(a) = self.point.take() {
+            PlotResult::Some(a)
+        } else if !self.done {
+            self.done = true;
+            PlotResult::None
+        } else {
+            PlotResult::Finished
+        }
+    }
+
+    fn next_name(&mut self, writer: &mut dyn fmt::Write) -> Option<fmt::Result> {
+        if !self.done {
+            Some(write!(writer, "{}", self.name))
+        } else {
+            None
+        }
+    }
+
+    fn next_details(&mut self) -> Option<PlotDetails> {
+        if !self.done {
+            Some(PlotDetails {
+                annotation: Some(self.style),
+                ..Default::default()
+            })
+        } else {
+            None
+        }
+    }
+}
@@ -32,26 +32,43 @@ pub fn gen_bar<K: Display, D: Display, X: PlotNum>(
     name: K,
     vals: impl IntoIterator<Item = (X, D)>,
     marker: impl IntoIterator<Item = X>,
+) -> (impl PlotIterator<X = X, Y = i128>, impl TickDistGen<i128>) {
+    gen_bar_with_fill(name, vals, marker, style::Fill::Solid)
+}
+
+///
+/// Like [`gen_bar`], but the bars are filled with a gradient or a pattern.
+///
+pub fn gen_bar_with_fill<K: Display, D: Display, X: PlotNum>(
+    name: K,
+    vals: impl IntoIterator<Item = (X, D)>,
+    marker: impl IntoIterator<Item = X>,
+    fill: style::Fill,
 ) -> (impl PlotIterator<X = X, Y = i128>, impl TickDistGen<i128>) {
     let (vals, names): (Vec<_>, Vec<_>) = vals.into_iter().unzip();
 
     let vals_len = vals.len();
 
-    let bars = build::plot(name).bars().buffered(
+    let bars = build::plot(name).bars().with_fill(fill).buffered(
         vals.into_iter()
             .enumerate()
             .map(|(i, x)| (x, i128::try_from(i).unwrap())),
     );
 
-    let ticks = (0..vals_len)
+    let m = build::markers(marker, [-1, i128::try_from(vals_len).unwrap()]);
+
+    (bars.chain(m), category_ticks(names))
+}
+
+///
+/// One tick per category, labeled with the category's name.
+/// The category at index `i` is plotted at `y=i`.
+///
+pub(super) fn category_ticks<D: Display>(names: Vec<D>) -> impl TickDistGen<i128> {
+    let ticks = (0..names.len())
         .map(|x| i128::try_from(x).unwrap())
         .collect::<Vec<_>>()
         .into_iter();
 
-    let m = build::markers(marker, [-1, i128::try_from(vals_len).unwrap()]);
-
-    (
-        bars.chain(m),
-        crate::ticks::TickDistribution::new(ticks).with_fmt(BarTickFmt { ticks: names }),
-    )
+    crate::ticks::TickDistribution::new(ticks).with_fmt(BarTickFmt { ticks: names })
 }
@@ -0,0 +1,81 @@
+//!
+//! Create hexagonal binning (hexbin) density plots
+//!
+//! Points are grouped into hexagons in screen space, so the size of the rendered
+//! SVG depends on the size of the plot area and not on the number of points.
+//!
+use super::*;
+
+///
+/// The size of a hexagon if the plot doesn't say.
+///
+pub(crate) const DEFAULT_SIZE: f64 = 10.0;
+
+///
+/// Builds a hexbin plot. Create one using [`SinglePlotBuilder::hexbin`].
+///
+pub struct HexbinBuilder<D: Display> {
+    label: D,
+    attrs: style::SeriesAttrs,
+    size: f64,
+}
+
+impl<D: Display> HexbinBuilder<D> {
+    pub(super) fn new(label: D, attrs: style::SeriesAttrs) -> Self {
+        HexbinBuilder {
+            label,
+            attrs,
+            size: DEFAULT_SIZE,
+        }
+    }
+
+    ///
+    /// The distance from the center of a hexagon to one of its corners in pixels.
+    /// The default is 10.
+    ///
+    pub fn size(mut self, size: f64) -> Self {
+        assert!(size > 0.0, "The hexagon size must be positive");
+        self.size = size;
+        self
+    }
+
+    pub fn buffered<X: PlotNum, Y: PlotNum, I: Iterator>(
+        self,
+        it: I,
+    ) -> SinglePlot<X, Y, std::vec::IntoIter<(X, Y)>, D>
+    where
+        I::Item: build::unwrapper::Unwrapper<Item = (X, Y)>,
+    {
+        PointBuilder {
+            attrs: self.attrs,
+            label: self.label,
+            typ: PlotMetaType::Plot(PlotType::Hexbin),
+            style: Default::default(),
+            details: PlotDetails {
+                hexbin_size: Some(self.size),
+                ..Default::default()
+            },
+        }
+        .buffered(it)
+    }
+
+    pub fn cloned<X: PlotNum, Y: PlotNum, I: Iterator + Clone>(
+        self,
+        it: I,
+    ) -> SinglePlot<X, Y, build::unwrapper::UnwrapperIter<I>, D>
+    where
+        I::Item: build::unwrapper::Unwrapper<Item = (X, Y)>,
+    {
+        PointBuilder {
+            attrs: self.attrs,
+            label: self.label,
+            typ: PlotMetaType::Plot(PlotType::Hexbin),
+            style: Default::default(),
+            details: PlotDetails {
+                hexbin_size: Some(self.size),
+                ..Default::default()
+            },
+        }
+        .cloned(it)
+    }
+}
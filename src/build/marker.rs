@@ -4,11 +4,27 @@ use super::*;
 pub struct Area<X, Y> {
     x: Option<[X; 2]>,
     y: Option<[Y; 2]>,
+    num_plots: usize,
 }
 
 impl<X: PlotNum, Y: PlotNum> Area<X, Y> {
     pub(crate) fn new() -> Area<X, Y> {
-        Area { x: None, y: None }
+        Area {
+            x: None,
+            y: None,
+            num_plots: 0,
+        }
+    }
+
+    ///
+    /// Count a plot that takes up a slot in the legend.
+    ///
+    pub(crate) fn add_plot(&mut self) {
+        self.num_plots += 1;
+    }
+
+    pub(crate) fn num_plots(&self) -> usize {
+        self.num_plots
     }
 
     pub fn grow_area(&mut self, other: &Area<X, Y>) {
@@ -5,10 +5,18 @@
 
 use super::*;
 
+pub mod annotation;
 pub mod bar;
 pub mod crop;
+pub mod hexbin;
 pub mod output_zip;
+pub mod quiver;
+pub mod radar;
+pub mod reference;
+pub mod regression;
+pub mod style;
 pub mod unwrapper;
+pub mod waterfall;
 use marker::Area;
 
 pub mod marker;
@@ -21,7 +29,11 @@ use unwrapper::Unwrapper;
 ///
 /// Determine how to interpret the plot's point data when rendering.
 ///
+/// More plot types may be added, so this enum is not exhaustive.
+/// Any data a plot type needs is passed along in [`PlotDetails`].
+///
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub enum PlotType {
     Scatter,
     Line,
@@ -29,6 +41,23 @@ pub enum PlotType {
     LineFill,
     LineFillRaw,
     Bars,
+    Quiver,
+    Waterfall,
+    Hexbin,
+    Reference,
+    Annotation,
+}
+
+///
+/// The extra data that some plot types need when rendering.
+/// Only the field that belongs to the plot's [`PlotType`] is read.
+///
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PlotDetails {
+    pub waterfall: Option<waterfall::WaterfallPart>,
+    pub hexbin_size: Option<f64>,
+    pub reference: Option<reference::RefKind>,
+    pub annotation: Option<annotation::AnnotationStyle>,
 }
 
 ///
@@ -51,6 +80,27 @@ pub trait PlotIterator {
     fn next_typ(&mut self) -> Option<PlotMetaType>;
     fn next_plot_point(&mut self) -> PlotResult<(Self::X, Self::Y)>;
     fn next_name(&mut self, w: &mut dyn fmt::Write) -> Option<fmt::Result>;
+
+    ///
+    /// The user's css classes, id and attributes of the current plot.
+    ///
+    fn next_attrs(&mut self) -> Option<style::SeriesAttrs> {
+        None
+    }
+
+    ///
+    /// The dash pattern, width, marker and fill of the current plot.
+    ///
+    fn next_style(&mut self) -> Option<style::SeriesStyle> {
+        None
+    }
+
+    ///
+    /// The data that the current plot's type needs, like the waterfall part or hexagon size.
+    ///
+    fn next_details(&mut self) -> Option<PlotDetails> {
+        None
+    }
 }
 
 ///
@@ -119,6 +169,30 @@ impl<'b, A> SinglePlotAccessor<'b, A> {
         self.flop.next_name(write)
     }
 
+    #[inline(always)]
+    pub fn attrs(&mut self) -> style::SeriesAttrs
+    where
+        A: PlotIterator,
+    {
+        self.flop.next_attrs().unwrap_or_default()
+    }
+
+    #[inline(always)]
+    pub fn style(&mut self) -> style::SeriesStyle
+    where
+        A: PlotIterator,
+    {
+        self.flop.next_style().unwrap_or_default()
+    }
+
+    #[inline(always)]
+    pub fn details(&mut self) -> PlotDetails
+    where
+        A: PlotIterator,
+    {
+        self.flop.next_details().unwrap_or_default()
+    }
+
     #[inline(always)]
     pub fn plots(&mut self) -> impl Iterator<Item = (A::X, A::Y)> + '_
     where
@@ -167,6 +241,34 @@ where
     plot_iter_impl::Marker::new(x, y)
 }
 
+///
+/// Draw an unnamed horizontal reference line. See [`SinglePlotBuilder::hline`].
+///
+pub fn hline<X: PlotNum, Y: PlotNum>(y: Y) -> reference::RefPlot<X, Y, &'static str> {
+    plot("").hline(y)
+}
+
+///
+/// Draw an unnamed vertical reference line. See [`SinglePlotBuilder::vline`].
+///
+pub fn vline<X: PlotNum, Y: PlotNum>(x: X) -> reference::RefPlot<X, Y, &'static str> {
+    plot("").vline(x)
+}
+
+///
+/// Draw an unnamed horizontal reference band. See [`SinglePlotBuilder::hband`].
+///
+pub fn hband<X: PlotNum, Y: PlotNum>(y: [Y; 2]) -> reference::RefPlot<X, Y, &'static str> {
+    plot("").hband(y)
+}
+
+///
+/// Draw an unnamed vertical reference band. See [`SinglePlotBuilder::vband`].
+///
+pub fn vband<X: PlotNum, Y: PlotNum>(x: [X; 2]) -> reference::RefPlot<X, Y, &'static str> {
+    plot("").vband(x)
+}
+
 ///
 /// Create a [`PlotsDyn`](plot_iter_impl::PlotsDyn)
 ///
@@ -203,11 +305,26 @@ impl<'a, X: PlotNum + 'a, Y: PlotNum + 'a> PlotIterator for BoxedPlot<'a, X, Y>
     fn next_typ(&mut self) -> Option<PlotMetaType> {
         self.inner.as_mut().next_typ()
     }
+
+    fn next_attrs(&mut self) -> Option<style::SeriesAttrs> {
+        self.inner.as_mut().next_attrs()
+    }
+
+    fn next_style(&mut self) -> Option<style::SeriesStyle> {
+        self.inner.as_mut().next_style()
+    }
+
+    fn next_details(&mut self) -> Option<PlotDetails> {
+        self.inner.as_mut().next_details()
+    }
 }
 
 pub struct PointBuilder<D: Display> {
     label: D,
+    attrs: style::SeriesAttrs,
     typ: PlotMetaType,
+    style: style::SeriesStyle,
+    details: PlotDetails,
 }
 
 impl<D: Display> PointBuilder<D> {
@@ -230,6 +347,9 @@ impl<D: Display> PointBuilder<D> {
             build::unwrapper::UnwrapperIter(it),
             area,
         )
+        .with_attrs(self.attrs)
+        .with_series_style(self.style)
+        .with_details(self.details)
     }
 
     pub fn buffered<X: PlotNum, Y: PlotNum, I: Iterator>(
@@ -247,27 +367,51 @@ impl<D: Display> PointBuilder<D> {
             vec.push((x, y));
         }
         SinglePlot::new(self.typ, self.label, vec.into_iter(), area)
+            .with_attrs(self.attrs)
+            .with_series_style(self.style)
+            .with_details(self.details)
     }
 }
 
 pub struct SinglePlotBuilder<D> {
     label: D,
+    attrs: style::SeriesAttrs,
 }
 
 impl<D: Display> SinglePlotBuilder<D> {
     /// Create a line from plots using a SVG path element.
-    /// The path element belongs to the `.poloto[N]fill` css class.  
+    /// The path element belongs to the `.poloto[N]stroke` and `.poloto[N]dash` css classes.
+    /// See [`style`] for how to style the line.
     pub fn line(self) -> PointBuilder<D> {
         PointBuilder {
             label: self.label,
+            attrs: self.attrs,
             typ: PlotMetaType::Plot(PlotType::Line),
+            style: Default::default(),
+            details: Default::default(),
         }
     }
 
     pub(crate) fn bars(self) -> PointBuilder<D> {
         PointBuilder {
             label: self.label,
+            attrs: self.attrs,
             typ: PlotMetaType::Plot(PlotType::Bars),
+            style: Default::default(),
+            details: Default::default(),
+        }
+    }
+
+    pub(crate) fn waterfall(self, part: waterfall::WaterfallPart) -> PointBuilder<D> {
+        PointBuilder {
+            label: self.label,
+            attrs: self.attrs,
+            typ: PlotMetaType::Plot(PlotType::Waterfall),
+            style: Default::default(),
+            details: PlotDetails {
+                waterfall: Some(part),
+                ..Default::default()
+            },
         }
     }
 
@@ -278,7 +422,10 @@ impl<D: Display> SinglePlotBuilder<D> {
     pub fn scatter(self) -> PointBuilder<D> {
         PointBuilder {
             label: self.label,
+            attrs: self.attrs,
             typ: PlotMetaType::Plot(PlotType::Scatter),
+            style: Default::default(),
+            details: Default::default(),
         }
     }
     /// Create a histogram from plots using SVG rect elements.
@@ -287,7 +434,10 @@ impl<D: Display> SinglePlotBuilder<D> {
     pub fn histogram(self) -> PointBuilder<D> {
         PointBuilder {
             label: self.label,
+            attrs: self.attrs,
             typ: PlotMetaType::Plot(PlotType::Histo),
+            style: Default::default(),
+            details: Default::default(),
         }
     }
 
@@ -296,7 +446,10 @@ impl<D: Display> SinglePlotBuilder<D> {
     pub fn line_fill(self) -> PointBuilder<D> {
         PointBuilder {
             label: self.label,
+            attrs: self.attrs,
             typ: PlotMetaType::Plot(PlotType::LineFill),
+            style: Default::default(),
+            details: Default::default(),
         }
     }
 
@@ -306,10 +459,36 @@ impl<D: Display> SinglePlotBuilder<D> {
     pub fn line_fill_raw(self) -> PointBuilder<D> {
         PointBuilder {
             label: self.label,
+            attrs: self.attrs,
             typ: PlotMetaType::Plot(PlotType::LineFillRaw),
+            style: Default::default(),
+            details: Default::default(),
         }
     }
 
+    /// Create a vector field from `[x, y, dx, dy]` vectors, drawing each vector as an arrow.
+    /// Each arrow is a SVG line element with a SVG marker arrowhead.
+    /// The lines belong to the `poloto_quiver` and `.poloto[N]stroke` css classes.
+    /// The arrowheads belong to the `.poloto[N]fill` css class.
+    pub fn quiver(self) -> quiver::QuiverBuilder<D> {
+        quiver::QuiverBuilder::new(self.label, self.attrs)
+    }
+
+    /// Create a density plot by grouping points into hexagons in screen space.
+    /// Each hexagon is a SVG path element colored by the number of points inside it, on the viridis scale.
+    /// The hexagons belong to the `poloto_hexbin` css class and are clipped to the plot area.
+    /// The legend shows a color bar from the fewest to the most points that belongs to the
+    /// `poloto_hexbin_colorbar` css class.
+    pub fn hexbin(self) -> hexbin::HexbinBuilder<D> {
+        hexbin::HexbinBuilder::new(self.label, self.attrs)
+    }
+
+    /// Fit a least-squares curve to plots and draw it as a line using a SVG path element.
+    /// The path element belongs to the `.poloto[N]stroke` css class.
+    pub fn trend(self, model: regression::Model) -> regression::TrendBuilder<D> {
+        regression::TrendBuilder::new(self.label, self.attrs, model)
+    }
+
     ///
     /// Write some text in the legend. This doesnt increment the plot number.
     ///
@@ -323,5 +502,8 @@ impl<D: Display> SinglePlotBuilder<D> {
     }
 }
 pub fn plot<D: Display>(label: D) -> SinglePlotBuilder<D> {
-    SinglePlotBuilder { label }
+    SinglePlotBuilder {
+        label,
+        attrs: style::SeriesAttrs::default(),
+    }
 }
@@ -11,6 +11,9 @@ pub struct SinglePlot<X, Y, I: Iterator<Item = (X, Y)>, D: Display> {
     area: Area<X, Y>,
     name: D,
     typ: PlotMetaType,
+    attrs: style::SeriesAttrs,
+    style: style::SeriesStyle,
+    details: PlotDetails,
     done: bool,
 }
 impl<X, Y, I: Iterator<Item = (X, Y)>, D: Display> SinglePlot<X, Y, I, D> {
@@ -21,9 +24,27 @@ impl<X, Y, I: Iterator<Item = (X, Y)>, D: Display> SinglePlot<X, Y, I, D> {
             area,
             name,
             typ,
+            attrs: style::SeriesAttrs::default(),
+            style: style::SeriesStyle::default(),
+            details: PlotDetails::default(),
             done: false,
         }
     }
+
+    pub(crate) fn with_attrs(mut self, attrs: style::SeriesAttrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub(crate) fn with_series_style(mut self, style: style::SeriesStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub(crate) fn with_details(mut self, details: PlotDetails) -> Self {
+        self.details = details;
+        self
+    }
 }
 
 impl<X: PlotNum, Y: PlotNum, I: Iterator<Item = (X, Y)>, D: Display> PlotIterator
@@ -33,6 +54,15 @@ impl<X: PlotNum, Y: PlotNum, I: Iterator<Item = (X, Y)>, D: Display> PlotIterato
     type Y = Y;
     fn increase_area(&mut self, area: &mut Area<X, Y>) {
         area.grow_area(&self.area);
+        //Reference lines and annotations are labeled inside the plot area instead of in the legend,
+        //and plots without a name don't have a legend entry.
+        let labeled = !matches!(
+            self.typ,
+            PlotMetaType::Plot(PlotType::Reference | PlotType::Annotation)
+        );
+        if labeled && !self.name.to_string().is_empty() {
+            area.add_plot();
+        }
     }
     #[inline(always)]
     fn next_plot_point(&mut self) -> PlotResult<(X, Y)> {
@@ -63,6 +93,30 @@ impl<X: PlotNum, Y: PlotNum, I: Iterator<Item = (X, Y)>, D: Display> PlotIterato
             None
         }
     }
+
+    fn next_attrs(&mut self) -> Option<style::SeriesAttrs> {
+        if !self.done {
+            Some(self.attrs.clone())
+        } else {
+            None
+        }
+    }
+
+    fn next_style(&mut self) -> Option<style::SeriesStyle> {
+        if !self.done {
+            Some(self.style)
+        } else {
+            None
+        }
+    }
+
+    fn next_details(&mut self) -> Option<PlotDetails> {
+        if !self.done {
+            Some(self.details)
+        } else {
+            None
+        }
+    }
 }
 
 ///
@@ -112,6 +166,31 @@ impl<A: PlotIterator, B: PlotIterator<X = A::X, Y = A::Y>> PlotIterator for Chai
             self.b.next_typ()
         }
     }
+
+    fn next_attrs(&mut self) -> Option<style::SeriesAttrs> {
+        //Ask the plot that is being rendered, even if it has no attributes.
+        if self.a.next_typ().is_some() {
+            self.a.next_attrs()
+        } else {
+            self.b.next_attrs()
+        }
+    }
+
+    fn next_style(&mut self) -> Option<style::SeriesStyle> {
+        if self.a.next_typ().is_some() {
+            self.a.next_style()
+        } else {
+            self.b.next_style()
+        }
+    }
+
+    fn next_details(&mut self) -> Option<PlotDetails> {
+        if self.a.next_typ().is_some() {
+            self.a.next_details()
+        } else {
+            self.b.next_details()
+        }
+    }
 }
 
 ///
@@ -163,6 +242,18 @@ impl<F: PlotIterator> PlotIterator for PlotsDyn<F> {
     fn next_name(&mut self, write: &mut dyn fmt::Write) -> Option<fmt::Result> {
         self.flop[self.counter].next_name(write)
     }
+
+    fn next_attrs(&mut self) -> Option<style::SeriesAttrs> {
+        self.flop.get_mut(self.counter)?.next_attrs()
+    }
+
+    fn next_style(&mut self) -> Option<style::SeriesStyle> {
+        self.flop.get_mut(self.counter)?.next_style()
+    }
+
+    fn next_details(&mut self) -> Option<PlotDetails> {
+        self.flop.get_mut(self.counter)?.next_details()
+    }
 }
 
 #[derive(Clone)]
@@ -0,0 +1,106 @@
+//!
+//! Create vector field (quiver) plots
+//!
+use super::*;
+
+///
+/// Builds a quiver plot from `[x, y, dx, dy]` vectors. Create one using [`SinglePlotBuilder::quiver`].
+///
+/// Each vector is drawn as an arrow from `(x, y)` to `(x + dx * scale, y + dy * scale)`.
+///
+pub struct QuiverBuilder<D: Display> {
+    label: D,
+    attrs: style::SeriesAttrs,
+    scale: Option<f64>,
+}
+
+impl<D: Display> QuiverBuilder<D> {
+    pub(super) fn new(label: D, attrs: style::SeriesAttrs) -> Self {
+        QuiverBuilder {
+            label,
+            attrs,
+            scale: None,
+        }
+    }
+
+    ///
+    /// Scale every vector by a fixed factor.
+    ///
+    /// By default, the vectors are scaled so that the longest vector
+    /// is no longer than the spacing between the points of the grid.
+    ///
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    pub fn buffered<I: IntoIterator>(
+        self,
+        it: I,
+    ) -> SinglePlot<f64, f64, std::vec::IntoIter<(f64, f64)>, D>
+    where
+        I::Item: Into<[f64; 4]>,
+    {
+        let vectors: Vec<[f64; 4]> = it.into_iter().map(|x| x.into()).collect();
+
+        let scale = if let Some(scale) = self.scale {
+            scale
+        } else {
+            auto_scale(&vectors)
+        };
+
+        let mut vec = Vec::with_capacity(vectors.len() * 2);
+        let mut area = Area::new();
+        for [x, y, dx, dy] in vectors {
+            let tail = (x, y);
+            let head = (x + dx * scale, y + dy * scale);
+            area.grow(Some(&tail.0), Some(&tail.1));
+            area.grow(Some(&head.0), Some(&head.1));
+            vec.push(tail);
+            vec.push(head);
+        }
+
+        SinglePlot::new(
+            PlotMetaType::Plot(PlotType::Quiver),
+            self.label,
+            vec.into_iter(),
+            area,
+        )
+        .with_attrs(self.attrs)
+    }
+}
+
+///
+/// Find a scale such that no vector extends past a neighbouring grid point.
+///
+fn auto_scale(vectors: &[[f64; 4]]) -> f64 {
+    fn min_spacing(mut vals: Vec<f64>) -> Option<f64> {
+        vals.retain(|x| x.is_finite());
+        vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        vals.windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|&d| d > 0.0)
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+    }
+
+    fn max_abs(vals: impl Iterator<Item = f64>) -> f64 {
+        vals.filter(|x| x.is_finite())
+            .map(f64::abs)
+            .fold(0.0, f64::max)
+    }
+
+    let spacingx = min_spacing(vectors.iter().map(|v| v[0]).collect());
+    let spacingy = min_spacing(vectors.iter().map(|v| v[1]).collect());
+    let maxdx = max_abs(vectors.iter().map(|v| v[2]));
+    let maxdy = max_abs(vectors.iter().map(|v| v[3]));
+
+    let scalex = spacingx.filter(|_| maxdx > 0.0).map(|s| s / maxdx);
+    let scaley = spacingy.filter(|_| maxdy > 0.0).map(|s| s / maxdy);
+
+    match (scalex, scaley) {
+        (Some(a), Some(b)) => a.min(b),
+        (Some(a), None) => a,
+        (None, Some(b)) => b,
+        (None, None) => 1.0,
+    }
+}
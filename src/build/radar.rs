@@ -0,0 +1,369 @@
+//!
+//! Create radar (spider) charts
+//!
+//! Each axis is given a name and its own range. Every series is a list of values,
+//! one per axis, and is drawn as a closed polygon.
+//!
+use super::*;
+
+struct RadarAxis<'a> {
+    name: Box<dyn Display + 'a>,
+    range: [f64; 2],
+}
+
+struct RadarSeries<'a> {
+    name: Box<dyn Display + 'a>,
+    values: Vec<f64>,
+    fill: bool,
+}
+
+///
+/// A radar chart. Create one using [`axes`].
+///
+pub struct Radar<'a> {
+    axes: Vec<RadarAxis<'a>>,
+    series: Vec<RadarSeries<'a>>,
+    opt: RenderOptions,
+    rings: usize,
+}
+
+///
+/// Start a radar chart with the specified named axes. Each axis has its own `[min,max]` range.
+/// Values outside of the range are clamped to it.
+///
+/// Panics if there are fewer than three axes, or if a range is not finite with `min < max`.
+/// The first axis points up and the rest are placed clockwise around the circle.
+///
+/// ```
+/// let r = poloto::build::radar::axes([("size", [0.0, 10.0]), ("speed", [0.0, 100.0]), ("memory", [0.0, 4.0])])
+///     .line("debug", [8.0, 20.0, 3.0])
+///     .line_fill("release", [5.0, 90.0, 2.0]);
+/// ```
+///
+pub fn axes<'a, D: Display + 'a>(axes: impl IntoIterator<Item = (D, [f64; 2])>) -> Radar<'a> {
+    let axes: Vec<_> = axes
+        .into_iter()
+        .map(|(name, range)| RadarAxis {
+            name: Box::new(name),
+            range,
+        })
+        .collect();
+
+    assert!(axes.len() >= 3, "A radar chart needs at least three axes");
+    for axis in axes.iter() {
+        let [min, max] = axis.range;
+        assert!(
+            min.is_finite() && max.is_finite() && min < max,
+            "The range of a radar axis must be finite with min < max"
+        );
+    }
+
+    Radar {
+        axes,
+        series: Vec::new(),
+        opt: render_opt(),
+        rings: 4,
+    }
+}
+
+impl<'a> Radar<'a> {
+    /// Add a series drawn as a closed polygon outline.
+    /// The polygon belongs to the `poloto_radar` and `.poloto[N]stroke` css classes.
+    pub fn line<D: Display + 'a>(self, name: D, values: impl IntoIterator<Item = f64>) -> Self {
+        self.add(name, values, false)
+    }
+
+    /// Add a series drawn as a closed polygon that is filled in.
+    /// The polygon belongs to the `poloto_radar`, `.poloto[N]stroke` and `.poloto[N]fill` css classes.
+    pub fn line_fill<D: Display + 'a>(
+        self,
+        name: D,
+        values: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        self.add(name, values, true)
+    }
+
+    fn add<D: Display + 'a>(
+        mut self,
+        name: D,
+        values: impl IntoIterator<Item = f64>,
+        fill: bool,
+    ) -> Self {
+        let values: Vec<_> = values.into_iter().collect();
+        assert_eq!(
+            values.len(),
+            self.axes.len(),
+            "There must be exactly one value per axis"
+        );
+        self.series.push(RadarSeries {
+            name: Box::new(name),
+            values,
+            fill,
+        });
+        self
+    }
+
+    ///
+    /// The number of concentric grid rings. The default is 4.
+    ///
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings.max(1);
+        self
+    }
+
+    pub fn map_opt<F: FnOnce(RenderOptions) -> RenderOptions>(mut self, func: F) -> Self {
+        self.opt = func(self.opt);
+        self
+    }
+
+    pub fn label<T: Display>(self, title: T) -> RadarLabeled<'a, T> {
+        RadarLabeled { radar: self, title }
+    }
+}
+
+///
+/// A radar chart with a title, ready to be rendered.
+///
+pub struct RadarLabeled<'a, T> {
+    radar: Radar<'a>,
+    title: T,
+}
+
+impl<'a, T: Display> RadarLabeled<'a, T> {
+    pub fn append_to<E: Elem>(self, elem: E) -> Stage4<elem::Append<E, Self>> {
+        Stage4(elem.append(self))
+    }
+
+    pub fn headless(self) -> Stage4<Self> {
+        Stage4(self)
+    }
+}
+
+impl<'a, T> elem::Locked for RadarLabeled<'a, T> {}
+
+impl<'a, T: Display> Elem for RadarLabeled<'a, T> {
+    type Tail = ();
+    fn render_head(self, writer: &mut elem::ElemWrite) -> Result<Self::Tail, fmt::Error> {
+        let RadarLabeled {
+            radar:
+                Radar {
+                    axes,
+                    series,
+                    mut opt,
+                    rings,
+                },
+            title,
+        } = self;
+
+        let canvas = opt.compute();
+        let RenderOptionsResult {
+            width,
+            height,
+            padding,
+            paddingy,
+            spacing,
+            legendx1,
+            legend_width,
+            margin_top,
+            num_css_classes,
+            precision,
+            ..
+        } = canvas;
+
+        let ffmt = crate::render::FloatFmt::new(precision);
+
+        writer.render(
+            hbuild::single("circle").with(attrs!(("r", "1e5"), ("class", "poloto_background"))),
+        )?;
+
+        writer.render(
+            hbuild::elem("text")
+                .with(attrs!(
+                    ("class", "poloto_labels poloto_text poloto_title"),
+                    ("x", width / 2.0),
+                    ("y", padding / 4.0)
+                ))
+                .append(title),
+        )?;
+
+        // The legend takes up the right margin, so center the chart in what is left.
+        let cx = (width - padding) / 2.0;
+        let cy = height / 2.0 + paddingy / 4.0;
+        let radius = (cx - padding / 2.0).min(cy - paddingy).max(0.0);
+
+        let num = axes.len();
+        let point = |i: usize, r: f64| {
+            let angle =
+                std::f64::consts::TAU * (i as f64) / (num as f64) - std::f64::consts::FRAC_PI_2;
+            [cx + r * angle.cos(), cy + r * angle.sin()]
+        };
+
+        use attr::PathCommand::*;
+
+        for ring in 1..=rings {
+            let r = radius * (ring as f64) / (rings as f64);
+            writer.render(hbuild::single("path").with(attrs!(
+                ("class", "poloto_tick_line poloto_radar_ring"),
+                ("stroke", "black"),
+                ("fill", "none"),
+                hbuild::path_from_closure(|w| {
+                    let mut w = w.start();
+                    for i in 0..num {
+                        let [x, y] = point(i, r);
+                        if i == 0 {
+                            w.put(M(ffmt.disp(x), ffmt.disp(y)))?;
+                        } else {
+                            w.put(L(ffmt.disp(x), ffmt.disp(y)))?;
+                        }
+                    }
+                    w.put(Z())
+                })
+            )))?;
+        }
+
+        for (i, axis) in axes.iter().enumerate() {
+            let [x, y] = point(i, radius);
+            writer.render(hbuild::single("line").with(attrs!(
+                ("class", "poloto_axis_lines poloto_radar_axis"),
+                ("stroke", "black"),
+                ("x1", ffmt.disp(cx)),
+                ("y1", ffmt.disp(cy)),
+                ("x2", ffmt.disp(x)),
+                ("y2", ffmt.disp(y))
+            )))?;
+
+            let [lx, ly] = point(i, radius + paddingy * 0.3);
+            let anchor = if (lx - cx).abs() < 1.0 {
+                "middle"
+            } else if lx > cx {
+                "start"
+            } else {
+                "end"
+            };
+
+            let name = &axis.name;
+            writer.render(
+                hbuild::elem("text")
+                    .with(attrs!(
+                        ("class", "poloto_labels poloto_text poloto_radar_axis_name"),
+                        ("dominant-baseline", "middle"),
+                        ("text-anchor", anchor),
+                        ("x", ffmt.disp(lx)),
+                        ("y", ffmt.disp(ly))
+                    ))
+                    .append(util::disp_const(move |f| write!(f, "{}", name))),
+            )?;
+
+            let [min, max] = axis.range;
+            let step = (max - min) / (rings as f64);
+            for ring in 1..=rings {
+                let r = radius * (ring as f64) / (rings as f64);
+                let [tx, ty] = point(i, r);
+                let val = min + step * (ring as f64);
+                writer.render(
+                    hbuild::elem("text")
+                        .with(attrs!(
+                            ("class", "poloto_tick_labels poloto_text"),
+                            ("dominant-baseline", "middle"),
+                            ("text-anchor", "start"),
+                            ("x", ffmt.disp(tx + padding / 30.0)),
+                            ("y", ffmt.disp(ty))
+                        ))
+                        .append(util::disp_const(move |f| {
+                            util::write_interval_float(f, val, Some(step))
+                        })),
+                )?;
+            }
+        }
+
+        let mut color_iter = {
+            let max = if let Some(nn) = num_css_classes {
+                nn
+            } else {
+                usize::MAX
+            };
+
+            (0..max).cycle()
+        };
+
+        for (i, s) in series.iter().enumerate() {
+            let colori = color_iter.next().unwrap();
+
+            let legendy1 = margin_top - padding / 8.0 + (i as f64) * spacing;
+
+            let name = &s.name;
+            let text = hbuild::elem("text").with(attrs!(
+                ("class", "poloto_text poloto_legend_text"),
+                ("x", width - legend_width),
+                ("y", margin_top + (i as f64) * spacing)
+            ));
+
+            let name_exists = text.render_closure(writer, |w| {
+                use std::fmt::Write;
+                let mut wc = util::WriteCounter::new(w.writer());
+                write!(wc, "{}", name)?;
+                Ok(wc.get_counter() != 0)
+            })?;
+
+            let (class, fill) = if s.fill {
+                (
+                    format!(
+                        "poloto_radar poloto_linefill poloto{}stroke poloto{}fill",
+                        colori, colori
+                    ),
+                    "black",
+                )
+            } else {
+                (
+                    format!("poloto_radar poloto_line poloto{}stroke", colori),
+                    "none",
+                )
+            };
+
+            let class = class.as_str();
+
+            if name_exists {
+                writer.render(hbuild::single("rect").with(attrs!(
+                    (
+                        "class",
+                        format_move!("{} poloto_legend_icon poloto{}legend", class, colori)
+                    ),
+                    ("stroke", "black"),
+                    ("fill", fill),
+                    ("fill-opacity", 0.4),
+                    ("x", legendx1),
+                    ("y", legendy1 - padding / 30.0),
+                    ("width", padding / 3.0),
+                    ("height", padding / 20.0),
+                    ("rx", padding / 30.0),
+                    ("ry", padding / 30.0)
+                )))?;
+            }
+
+            writer.render(hbuild::single("path").with(attrs!(
+                ("class", class),
+                ("stroke", "black"),
+                ("fill", fill),
+                ("fill-opacity", 0.4),
+                hbuild::path_from_closure(|w| {
+                    let mut w = w.start();
+                    for (j, (axis, &val)) in axes.iter().zip(s.values.iter()).enumerate() {
+                        let [min, max] = axis.range;
+                        let val = if val.is_finite() { val } else { min };
+                        //Values outside the range are drawn on the center or the outer ring.
+                        let frac = ((val - min) / (max - min)).clamp(0.0, 1.0);
+                        let [x, y] = point(j, radius * frac);
+                        if j == 0 {
+                            w.put(M(ffmt.disp(x), ffmt.disp(y)))?;
+                        } else {
+                            w.put(L(ffmt.disp(x), ffmt.disp(y)))?;
+                        }
+                    }
+                    w.put(Z())
+                })
+            )))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//!
+//! Reference lines and shaded bands that span the whole plot area
+//!
+//! Unlike [`markers`](super::markers), these are drawn. They are useful for things like
+//! thresholds, deploy times and maintenance windows. A reference line or band isn't
+//! given a legend entry. Instead its name is written next to it inside the plot area.
+//! Reference lines and bands are not drawn in polar mode.
+//!
+use super::*;
+
+///
+/// Which kind of reference a plot renders.
+///
+#[derive(Copy, Clone, Debug)]
+pub enum RefKind {
+    HLine,
+    VLine,
+    HBand,
+    VBand,
+}
+
+impl<D: Display> SinglePlotBuilder<D> {
+    ///
+    /// Draw a horizontal line across the plot area at `y`.
+    /// The line is a SVG line element that belongs to the `poloto_refline` and `poloto_hline` css classes.
+    ///
+    pub fn hline<X: PlotNum, Y: PlotNum>(self, y: Y) -> RefPlot<X, Y, D> {
+        let x = X::unit_range(None)[0];
+        self.reference(RefKind::HLine, vec![(x, y)], None, Some([y, y]))
+    }
+
+    ///
+    /// Draw a vertical line across the plot area at `x`.
+    /// The line is a SVG line element that belongs to the `poloto_refline` and `poloto_vline` css classes.
+    ///
+    pub fn vline<X: PlotNum, Y: PlotNum>(self, x: X) -> RefPlot<X, Y, D> {
+        let y = Y::unit_range(None)[0];
+        self.reference(RefKind::VLine, vec![(x, y)], Some([x, x]), None)
+    }
+
+    ///
+    /// Shade the plot area between two y values.
+    /// The band is a SVG rect element that belongs to the `poloto_refband` and `poloto_hband` css classes.
+    ///
+    pub fn hband<X: PlotNum, Y: PlotNum>(self, [y0, y1]: [Y; 2]) -> RefPlot<X, Y, D> {
+        let x = X::unit_range(None)[0];
+        self.reference(RefKind::HBand, vec![(x, y0), (x, y1)], None, Some([y0, y1]))
+    }
+
+    ///
+    /// Shade the plot area between two x values.
+    /// The band is a SVG rect element that belongs to the `poloto_refband` and `poloto_vband` css classes.
+    ///
+    pub fn vband<X: PlotNum, Y: PlotNum>(self, [x0, x1]: [X; 2]) -> RefPlot<X, Y, D> {
+        let y = Y::unit_range(None)[0];
+        self.reference(RefKind::VBand, vec![(x0, y), (x1, y)], Some([x0, x1]), None)
+    }
+
+    fn reference<X: PlotNum, Y: PlotNum>(
+        self,
+        kind: RefKind,
+        points: Vec<(X, Y)>,
+        x: Option<[X; 2]>,
+        y: Option<[Y; 2]>,
+    ) -> RefPlot<X, Y, D> {
+        //Only the coordinates that were specified should affect the bounds.
+        let mut area = Area::new();
+        for x in x.iter().flatten() {
+            area.grow(Some(x), None);
+        }
+        for y in y.iter().flatten() {
+            area.grow(None, Some(y));
+        }
+
+        SinglePlot::new(
+            PlotMetaType::Plot(PlotType::Reference),
+            self.label,
+            points.into_iter(),
+            area,
+        )
+        .with_details(PlotDetails {
+            reference: Some(kind),
+            ..Default::default()
+        })
+    }
+}
+
+///
+/// A reference line or band.
+///
+pub type RefPlot<X, Y, D> = SinglePlot<X, Y, std::vec::IntoIter<(X, Y)>, D>;
@@ -0,0 +1,358 @@
+//!
+//! Fit least-squares trendlines to plot data
+//!
+//! ```
+//! use poloto::build::regression::Model;
+//! let data = [(0.0, 1.0), (1.0, 3.1), (2.0, 4.9), (3.0, 7.2)];
+//! let fit = Model::Linear.fit(data).unwrap();
+//! assert!(fit.r_squared() > 0.99);
+//! ```
+//!
+use super::*;
+
+///
+/// The kind of curve to fit to the data.
+///
+#[derive(Copy, Clone, Debug)]
+pub enum Model {
+    /// `y = a + bx`
+    Linear,
+    /// `y = a + bx + cx^2 + ...` of the specified degree.
+    Polynomial(usize),
+    /// `y = a*e^(bx)`. Every `y` value must be positive.
+    Exponential,
+}
+
+impl Model {
+    ///
+    /// Fit the model to the points using least squares.
+    ///
+    /// Returns `None` if there are not enough distinct points to determine the curve,
+    /// or if the model is exponential and not every `y` value is positive.
+    /// Points with non-finite coordinates are ignored.
+    ///
+    pub fn fit<I: IntoIterator>(self, points: I) -> Option<Fit>
+    where
+        I::Item: build::unwrapper::Unwrapper<Item = (f64, f64)>,
+    {
+        let points: Vec<_> = points
+            .into_iter()
+            .map(|a| a.unwrap())
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+
+        let coefficients = match self {
+            Model::Linear => least_squares(&points, 1)?,
+            Model::Polynomial(degree) => least_squares(&points, degree)?,
+            Model::Exponential => {
+                if points.iter().any(|&(_, y)| y <= 0.0) {
+                    return None;
+                }
+                let logs: Vec<_> = points.iter().map(|&(x, y)| (x, y.ln())).collect();
+                let c = least_squares(&logs, 1)?;
+                vec![c[0].exp(), c[1]]
+            }
+        };
+
+        let mut fit = Fit {
+            model: self,
+            coefficients,
+            r_squared: 0.0,
+        };
+
+        let mean = points.iter().map(|&(_, y)| y).sum::<f64>() / points.len() as f64;
+        let (res, tot) = points.iter().fold((0.0, 0.0), |(res, tot), &(x, y)| {
+            (res + (y - fit.eval(x)).powi(2), tot + (y - mean).powi(2))
+        });
+
+        fit.r_squared = if tot > 0.0 { 1.0 - res / tot } else { 1.0 };
+        Some(fit)
+    }
+}
+
+///
+/// A curve fitted to some data using [`Model::fit`].
+///
+/// Formatting it with [`Display`] writes its equation.
+///
+#[derive(Clone, Debug)]
+pub struct Fit {
+    model: Model,
+    coefficients: Vec<f64>,
+    r_squared: f64,
+}
+
+impl Fit {
+    pub fn model(&self) -> Model {
+        self.model
+    }
+
+    ///
+    /// For linear and polynomial models, the coefficients in order of increasing power of `x`.
+    /// For exponential models, `[a, b]` in `y = a*e^(bx)`.
+    ///
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    ///
+    /// The coefficient of determination. `1.0` means the curve fits the data perfectly.
+    ///
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        match self.model {
+            Model::Exponential => self.coefficients[0] * (self.coefficients[1] * x).exp(),
+            _ => self
+                .coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |acc, &c| acc * x + c),
+        }
+    }
+}
+
+impl Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn coef(f: &mut fmt::Formatter, c: f64) -> fmt::Result {
+            let step = if c == 0.0 {
+                None
+            } else {
+                Some(c.abs() / 1000.0)
+            };
+            util::write_interval_float(f, c, step)
+        }
+
+        write!(f, "y = ")?;
+
+        if let Model::Exponential = self.model {
+            coef(f, self.coefficients[0])?;
+            write!(f, "e^(")?;
+            coef(f, self.coefficients[1])?;
+            return write!(f, "x)");
+        }
+
+        let mut first = true;
+        for (power, &c) in self.coefficients.iter().enumerate().rev() {
+            if c == 0.0 && !(first && power == 0) {
+                continue;
+            }
+
+            if first {
+                coef(f, c)?;
+            } else {
+                write!(f, " {} ", if c < 0.0 { '-' } else { '+' })?;
+                coef(f, c.abs())?;
+            }
+            first = false;
+
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", power)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+///
+/// Solve the normal equations for a polynomial of the specified degree.
+///
+/// The x values are centered and scaled to `[-1, 1]` first, so the fit works the same
+/// no matter the scale of x. The coefficients are then mapped back to powers of x.
+///
+fn least_squares(points: &[(f64, f64)], degree: usize) -> Option<Vec<f64>> {
+    let n = degree + 1;
+    if points.len() < n {
+        return None;
+    }
+
+    let center = points.iter().map(|&(x, _)| x).sum::<f64>() / points.len() as f64;
+    let scale = points
+        .iter()
+        .map(|&(x, _)| (x - center).abs())
+        .fold(0.0, f64::max);
+    let scale = if scale > 0.0 { scale } else { 1.0 };
+
+    //Augmented matrix of the normal equations.
+    let mut m = vec![vec![0.0; n + 1]; n];
+    for &(x, y) in points {
+        let u = (x - center) / scale;
+        let powers: Vec<f64> = (0..2 * n).map(|p| u.powi(p as i32)).collect();
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, a) in row[..n].iter_mut().enumerate() {
+                *a += powers[i + j];
+            }
+            row[n] += powers[i] * y;
+        }
+    }
+
+    if m.iter().flatten().any(|a| !a.is_finite()) {
+        return None;
+    }
+
+    //A pivot this small compared to the matrix means the points can't determine the curve.
+    let norm = m
+        .iter()
+        .flat_map(|row| row[..n].iter())
+        .fold(0.0, |acc: f64, a| acc.max(a.abs()));
+    let tolerance = norm * 1e-12;
+
+    //Gaussian elimination with partial pivoting.
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))?;
+        if m[pivot][col].abs() <= tolerance {
+            return None;
+        }
+        m.swap(col, pivot);
+
+        let (above, below) = m.split_at_mut(col + 1);
+        let pivot_row = &above[col];
+        for row in below.iter_mut() {
+            let factor = row[col] / pivot_row[col];
+            for (a, &p) in row[col..].iter_mut().zip(pivot_row[col..].iter()) {
+                *a -= factor * p;
+            }
+        }
+    }
+
+    let mut scaled = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| m[row][k] * scaled[k]).sum();
+        scaled[row] = (m[row][n] - sum) / m[row][row];
+    }
+
+    //Expand the polynomial in u = (x - center) / scale using Horner's method.
+    let mut coefficients = vec![0.0; n];
+    for &a in scaled.iter().rev() {
+        //Multiply by (x - center) / scale, then add a.
+        let mut next = vec![0.0; n];
+        for (k, &c) in coefficients.iter().enumerate() {
+            if k + 1 < n {
+                next[k + 1] += c / scale;
+            }
+            next[k] -= c * center / scale;
+        }
+        next[0] += a;
+        coefficients = next;
+    }
+
+    if coefficients.iter().all(|c| c.is_finite()) {
+        Some(coefficients)
+    } else {
+        None
+    }
+}
+
+///
+/// The legend text of a trendline. Create one using [`SinglePlotBuilder::trend`].
+///
+pub struct TrendName<D> {
+    name: D,
+    fit: Option<Fit>,
+    equation: bool,
+}
+
+impl<D: Display> Display for TrendName<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        if let (true, Some(fit)) = (self.equation, &self.fit) {
+            write!(f, " {} (R² = ", fit)?;
+            util::write_interval_float(&mut *f, fit.r_squared(), Some(0.001))?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Builds a trendline. Create one using [`SinglePlotBuilder::trend`].
+///
+pub struct TrendBuilder<D> {
+    label: D,
+    attrs: style::SeriesAttrs,
+    model: Model,
+    samples: usize,
+    equation: bool,
+}
+
+impl<D: Display> TrendBuilder<D> {
+    pub(super) fn new(label: D, attrs: style::SeriesAttrs, model: Model) -> Self {
+        TrendBuilder {
+            label,
+            attrs,
+            model,
+            samples: 100,
+            equation: false,
+        }
+    }
+
+    ///
+    /// Write the equation of the fitted curve and its R² value in the legend after the name.
+    ///
+    pub fn with_equation(mut self) -> Self {
+        self.equation = true;
+        self
+    }
+
+    ///
+    /// The number of points used to draw the fitted curve. The default is 100.
+    ///
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(2);
+        self
+    }
+
+    ///
+    /// Fit the curve to the points and create a line plot of it spanning the same x range as the points.
+    ///
+    /// If the curve could not be fitted, the plot is empty.
+    ///
+    pub fn buffered<I: IntoIterator>(
+        self,
+        points: I,
+    ) -> SinglePlot<f64, f64, std::vec::IntoIter<(f64, f64)>, TrendName<D>>
+    where
+        I::Item: build::unwrapper::Unwrapper<Item = (f64, f64)>,
+    {
+        let points: Vec<_> = points.into_iter().map(|a| a.unwrap()).collect();
+
+        let fit = self.model.fit(points.iter().copied());
+
+        let range = points
+            .iter()
+            .map(|&(x, _)| x)
+            .filter(|x| x.is_finite())
+            .fold(None, |acc: Option<[f64; 2]>, x| match acc {
+                Some([min, max]) => Some([min.min(x), max.max(x)]),
+                None => Some([x, x]),
+            });
+
+        let mut vec = Vec::new();
+        if let (Some(fit), Some([min, max])) = (&fit, range) {
+            for i in 0..self.samples {
+                let x = min + (max - min) * (i as f64) / ((self.samples - 1) as f64);
+                vec.push((x, fit.eval(x)));
+            }
+        }
+
+        let name = TrendName {
+            name: self.label,
+            fit,
+            equation: self.equation,
+        };
+
+        PointBuilder {
+            attrs: self.attrs,
+            label: name,
+            typ: PlotMetaType::Plot(PlotType::Line),
+            style: Default::default(),
+            details: Default::default(),
+        }
+        .buffered(vec.into_iter())
+    }
+}
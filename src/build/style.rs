@@ -0,0 +1,284 @@
+//!
+//! Per plot line styles, so plots can be told apart without color
+//!
+//! Every line belongs to a `.poloto[N]dash` css class, so a theme can give each plot
+//! a different dash pattern (see [`ThemeBuilder::with_dashes`](crate::render::theme::ThemeBuilder::with_dashes)).
+//! A style chosen here is written inline, so it takes precedence over the theme.
+//!
+//! Plots can also be given their own css classes, id and attributes, so that they can be styled
+//! without knowing their plot number `N`. These are supported by the plots made from a [`PointBuilder`],
+//! as well as quiver, hexbin and [`waterfall`](super::waterfall) plots.
+//!
+use super::*;
+
+///
+/// A dash pattern of a line.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Dash {
+    Solid,
+    Dashed,
+    Dotted,
+    DashDot,
+    LongDash,
+}
+
+impl Dash {
+    ///
+    /// All the patterns, in the order they are cycled through.
+    ///
+    pub const ALL: [Dash; 5] = [
+        Dash::Solid,
+        Dash::Dashed,
+        Dash::Dotted,
+        Dash::DashDot,
+        Dash::LongDash,
+    ];
+
+    ///
+    /// The value of the `stroke-dasharray` css property.
+    ///
+    pub fn dasharray(&self) -> &'static str {
+        match self {
+            Dash::Solid => "none",
+            Dash::Dashed => "10 6",
+            Dash::Dotted => "2 6",
+            Dash::DashDot => "10 5 2 5",
+            Dash::LongDash => "20 8",
+        }
+    }
+}
+
+///
+/// The shape drawn at each point of a scatter plot, or at each point of a line.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MarkerShape {
+    Circle,
+    Square,
+    Triangle,
+    Diamond,
+}
+
+impl MarkerShape {
+    ///
+    /// All the shapes, in the order they are cycled through
+    /// if enabled with [`RenderOptions::with_auto_markers`](crate::render::RenderOptions::with_auto_markers).
+    ///
+    pub const ALL: [MarkerShape; 4] = [
+        MarkerShape::Circle,
+        MarkerShape::Square,
+        MarkerShape::Triangle,
+        MarkerShape::Diamond,
+    ];
+}
+
+///
+/// How to fill the area of a line fill, histogram or bar plot.
+///
+/// The patterns and the gradient are written once into a SVG defs element, with ids that are prefixed
+/// per render. The filled shapes belong to the `poloto_fade`, `poloto_hatch`, `poloto_crosshatch` or
+/// `poloto_dots` css class, and patterns also to the `.poloto[N]hatch`, `.poloto[N]crosshatch` or
+/// `.poloto[N]dots` css class. A SVG style element next to the defs points these classes at the defs.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Fill {
+    /// A flat color.
+    Solid,
+    /// Fade out towards the bottom, becoming transparent at the baseline.
+    Fade,
+    /// Diagonal lines.
+    Hatch,
+    /// Diagonal lines in both directions.
+    CrossHatch,
+    /// A grid of dots.
+    Dots,
+}
+
+///
+/// The style of a plot. Set it using the `with_*` functions of [`PointBuilder`].
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SeriesStyle {
+    pub dash: Option<Dash>,
+    pub width: Option<f64>,
+    pub marker: Option<MarkerShape>,
+    pub fill: Option<Fill>,
+}
+
+impl SeriesStyle {
+    ///
+    /// The inline css of the dash and width, or an empty string if neither were chosen.
+    ///
+    pub(crate) fn inline_css(&self) -> String {
+        let mut s = String::new();
+        if let Some(dash) = self.dash {
+            s += &format!("stroke-dasharray:{};", dash.dasharray());
+        }
+        if let Some(width) = self.width {
+            s += &format!("stroke-width:{};", width);
+        }
+        s
+    }
+}
+
+///
+/// User css classes, an id and attributes of a plot. Set them using the `with_*` functions of [`SinglePlotBuilder`].
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SeriesAttrs {
+    class: Vec<String>,
+    id: Option<String>,
+    style: Option<String>,
+    attrs: Vec<(String, String)>,
+}
+
+impl SeriesAttrs {
+    ///
+    /// The classes, each with a leading space, to append to the class attribute.
+    ///
+    pub(crate) fn class(&self) -> String {
+        self.class.iter().map(|c| format!(" {}", c)).collect()
+    }
+
+    ///
+    /// The id of the plot, or of its legend icon.
+    ///
+    pub(crate) fn id(&self, legend: bool) -> Option<String> {
+        self.id.as_ref().map(|id| {
+            if legend {
+                format!("{}_legend", id)
+            } else {
+                id.clone()
+            }
+        })
+    }
+
+    pub(crate) fn style(&self) -> Option<&str> {
+        self.style.as_deref()
+    }
+
+    pub(crate) fn attrs(&self) -> &[(String, String)] {
+        &self.attrs
+    }
+
+    pub(crate) fn add_class(&mut self, class: &str) {
+        self.class.push(class.to_string());
+    }
+
+    pub(crate) fn set_id(&mut self, id: &str) {
+        self.id = Some(id.to_string());
+    }
+
+    pub(crate) fn set_style(&mut self, style: &str) {
+        self.style = Some(style.to_string());
+    }
+
+    pub(crate) fn add_attr(&mut self, name: &str, value: &str) {
+        self.attrs.push((name.to_string(), value.to_string()));
+    }
+
+    ///
+    /// The same attributes with `_suffix` appended to the id,
+    /// for when one builder makes multiple plots.
+    ///
+    pub(crate) fn with_id_suffix(&self, suffix: &str) -> SeriesAttrs {
+        SeriesAttrs {
+            id: self.id.as_ref().map(|id| format!("{}_{}", id, suffix)),
+            ..self.clone()
+        }
+    }
+}
+
+impl<D: Display> SinglePlotBuilder<D> {
+    ///
+    /// Add a css class to the plot and its legend icon, in addition to the `poloto[N]` classes.
+    /// Can be called multiple times.
+    ///
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.attrs.add_class(class);
+        self
+    }
+
+    ///
+    /// Give the plot an id. Its legend icon gets the same id with `_legend` appended.
+    ///
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.attrs.set_id(id);
+        self
+    }
+
+    ///
+    /// Inline css for the plot and its legend icon. It takes precedence over the theme.
+    ///
+    pub fn with_style(mut self, style: &str) -> Self {
+        self.attrs.set_style(style);
+        self
+    }
+
+    ///
+    /// Add an attribute to the plot and its legend icon. Can be called multiple times.
+    ///
+    pub fn with_attr(mut self, name: &str, value: &str) -> Self {
+        self.attrs.add_attr(name, value);
+        self
+    }
+}
+
+impl<D: Display> PointBuilder<D> {
+    fn style_mut(&mut self) -> Option<&mut SeriesStyle> {
+        match self.typ {
+            PlotMetaType::Plot(
+                PlotType::Line
+                | PlotType::Scatter
+                | PlotType::Histo
+                | PlotType::LineFill
+                | PlotType::LineFillRaw
+                | PlotType::Bars,
+            ) => Some(&mut self.style),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Draw the line with a dash pattern. Only has an effect on line plots.
+    ///
+    pub fn with_dash(mut self, dash: Dash) -> Self {
+        if let Some(style) = self.style_mut() {
+            style.dash = Some(dash);
+        }
+        self
+    }
+
+    ///
+    /// The width of a line, or the size of the points of a scatter plot.
+    ///
+    pub fn with_width(mut self, width: f64) -> Self {
+        if let Some(style) = self.style_mut() {
+            style.width = Some(width);
+        }
+        self
+    }
+
+    ///
+    /// Fill the area with a gradient or a pattern. Only has an effect on
+    /// line fill and histogram plots. For bar charts, see [`bar::gen_bar_with_fill`].
+    ///
+    pub fn with_fill(mut self, fill: Fill) -> Self {
+        if let Some(style) = self.style_mut() {
+            style.fill = Some(fill);
+        }
+        self
+    }
+
+    ///
+    /// Draw a shape at each point. The shapes belong to the `poloto_marker`
+    /// and `.poloto[N]fill` css classes.
+    ///
+    pub fn with_marker(mut self, marker: MarkerShape) -> Self {
+        if let Some(style) = self.style_mut() {
+            style.marker = Some(marker);
+        }
+        self
+    }
+}
@@ -0,0 +1,202 @@
+//!
+//! Create waterfall charts
+//!
+//! A waterfall chart shows how a starting value is changed by a series of
+//! positive and negative deltas. Like [`bar`](super::bar) charts, each step is a
+//! category on the y axis and the bars are horizontal.
+//!
+use super::*;
+
+///
+/// Which part of a waterfall chart a plot renders.
+///
+#[derive(Copy, Clone, Debug)]
+pub enum WaterfallPart {
+    Increase,
+    Decrease,
+    Total,
+    Connector,
+}
+
+enum Step<D> {
+    Delta(D, f64),
+    Total(D),
+}
+
+///
+/// Builds a waterfall chart over labelled deltas.
+///
+/// ```
+/// let data = poloto::build::waterfall::Waterfall::new()
+///     .delta("baseline", 120.0)
+///     .delta("serde", 35.0)
+///     .delta("strip symbols", -40.0)
+///     .total("total")
+///     .with_connectors()
+///     .gen_simple();
+/// ```
+///
+pub struct Waterfall<D> {
+    steps: Vec<Step<D>>,
+    connectors: bool,
+    attrs: style::SeriesAttrs,
+}
+
+impl<D: Display> Default for Waterfall<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: Display> Waterfall<D> {
+    pub fn new() -> Self {
+        Waterfall {
+            steps: Vec::new(),
+            connectors: false,
+            attrs: style::SeriesAttrs::default(),
+        }
+    }
+
+    ///
+    /// Change the running total by `delta`. Drawn as a floating bar.
+    ///
+    pub fn delta(mut self, name: D, delta: f64) -> Self {
+        self.steps.push(Step::Delta(name, delta));
+        self
+    }
+
+    ///
+    /// Add a bar from zero to the current running total.
+    ///
+    pub fn total(mut self, name: D) -> Self {
+        self.steps.push(Step::Total(name));
+        self
+    }
+
+    ///
+    /// Draw lines connecting the end of each bar to the start of the next one.
+    ///
+    pub fn with_connectors(mut self) -> Self {
+        self.connectors = true;
+        self
+    }
+
+    ///
+    /// Add a css class to the bars, connectors and legend icons. Can be called multiple times.
+    ///
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.attrs.add_class(class);
+        self
+    }
+
+    ///
+    /// Give the chart an id. Each part gets the id with `_increase`, `_decrease`, `_total`
+    /// or `_connector` appended, and each legend icon gets `_legend` appended after that.
+    ///
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.attrs.set_id(id);
+        self
+    }
+
+    ///
+    /// Inline css for the bars, connectors and legend icons. It takes precedence over the theme.
+    ///
+    pub fn with_style(mut self, style: &str) -> Self {
+        self.attrs.set_style(style);
+        self
+    }
+
+    ///
+    /// Add an attribute to the bars, connectors and legend icons. Can be called multiple times.
+    ///
+    pub fn with_attr(mut self, name: &str, value: &str) -> Self {
+        self.attrs.add_attr(name, value);
+        self
+    }
+
+    ///
+    /// Create the waterfall plots and a tick distribution for the y axis that labels each step.
+    ///
+    /// Each bar is a SVG rect element that belongs to the `poloto_waterfall` and `.poloto[N]fill` css classes
+    /// as well as one of `poloto_waterfall_increase`, `poloto_waterfall_decrease`, or `poloto_waterfall_total`.
+    /// The connector lines belong to the `poloto_waterfall_connector` css class.
+    ///
+    pub fn gen_bar(self) -> (impl PlotIterator<X = f64, Y = i128>, impl TickDistGen<i128>) {
+        let mut increase = Vec::new();
+        let mut decrease = Vec::new();
+        let mut total = Vec::new();
+        let mut connector = Vec::new();
+
+        let mut names = Vec::with_capacity(self.steps.len());
+        let mut running = 0.0;
+        for (i, step) in self.steps.into_iter().enumerate() {
+            let i = i128::try_from(i).unwrap();
+
+            if i > 0 {
+                connector.push((running, i - 1));
+                connector.push((running, i));
+            }
+
+            match step {
+                Step::Delta(name, delta) => {
+                    let bar = [(running, i), (running + delta, i)];
+                    if delta >= 0.0 {
+                        increase.extend(bar);
+                    } else {
+                        decrease.extend(bar);
+                    }
+                    running += delta;
+                    names.push(name);
+                }
+                Step::Total(name) => {
+                    total.extend([(0.0, i), (running, i)]);
+                    names.push(name);
+                }
+            }
+        }
+
+        if !self.connectors {
+            connector.clear();
+        }
+
+        let parts = [
+            ("increase", "increase", WaterfallPart::Increase, increase),
+            ("decrease", "decrease", WaterfallPart::Decrease, decrease),
+            ("total", "total", WaterfallPart::Total, total),
+            ("", "connector", WaterfallPart::Connector, connector),
+        ];
+
+        //Keep the parts with no bars so that every part always gets the same color,
+        //but without a name so that they don't show up in the legend.
+        let attrs = self.attrs;
+        let parts = parts.into_iter().map(move |(name, suffix, part, vals)| {
+            SinglePlotBuilder {
+                label: if vals.is_empty() { "" } else { name },
+                attrs: attrs.with_id_suffix(suffix),
+            }
+            .waterfall(part)
+            .buffered(vals.into_iter())
+        });
+
+        let num = i128::try_from(names.len()).unwrap();
+        let plots = build::plots_dyn(parts).chain(build::markers([0.0], [-1, num]));
+
+        (plots, bar::category_ticks(names))
+    }
+
+    pub fn gen_simple(
+        self,
+    ) -> Stage2<
+        impl PlotIterator<X = f64, Y = i128>,
+        impl TickDist<Num = f64>,
+        impl TickDist<Num = i128>,
+    > {
+        let (plots, ytick_fmt) = self.gen_bar();
+
+        let opt = crate::render::render_opt()
+            .with_tick_lines([true, false])
+            .move_into();
+
+        crate::render::Stage1::from_parts(plots, f64::default_ticks(), ytick_fmt, opt).build()
+    }
+}
@@ -67,8 +67,6 @@ use ticks::*;
 
 use render::*;
 
-mod simple;
-
 ///
 /// Macro to chain multiple plots together instead of calling [`chain`](build::PlotIteratorExt::chain) repeatedly.
 ///
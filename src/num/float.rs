@@ -16,6 +16,7 @@ pub struct FloatFmt {
     offset: Option<f64>,
     axis: Axis,
     step: f64,
+    minor: Vec<f64>,
 }
 impl FloatFmt {
     pub fn step(&self) -> &f64 {
@@ -59,6 +60,9 @@ impl crate::ticks::tick_fmt::TickFmt<f64> for FloatFmt {
             Ok(())
         }
     }
+    fn minor_ticks(&mut self) -> Vec<f64> {
+        self.minor.clone()
+    }
 }
 
 impl TickDistGen<f64> for FloatTickFmt {
@@ -75,6 +79,7 @@ impl TickDistGen<f64> for FloatTickFmt {
         let tick_layout = TickLayout::new(&[1, 2, 5], ideal_num_steps, range);
 
         let (offset, ticks) = tick_layout.generate();
+        let minor = tick_layout.generate_minor(range);
 
         let dash_size = compute_best_dash_1_2_5(
             tick_layout.step.scale(range, canvas.max),
@@ -93,6 +98,7 @@ impl TickDistGen<f64> for FloatTickFmt {
                 offset,
                 axis,
                 step: tick_layout.step,
+                minor,
             },
         }
     }
@@ -185,6 +191,28 @@ impl TickLayout {
         (display_relative, ticks)
     }
 
+    ///
+    /// Subdivide each step into four or five parts. Only the positions inside of the range
+    /// that don't land on a major tick are returned.
+    ///
+    fn generate_minor(&self, range: [f64; 2]) -> Vec<f64> {
+        let parts = if self.normalized_step == 2 { 4 } else { 5 };
+        let minor_step = self.step / (parts as f64);
+        let start = self.start_tick - self.step;
+
+        let mut ticks = Vec::new();
+        for a in 1u32.. {
+            let position = start + minor_step * (a as f64);
+            if position > range[1] {
+                break;
+            }
+            if a % parts != 0 && position >= range[0] {
+                ticks.push(position);
+            }
+        }
+        ticks
+    }
+
     fn new(good_steps: &[u32], ideal_num_steps: u32, range_all: [f64; 2]) -> TickLayout {
         let ideal_num_steps = ideal_num_steps.max(2);
 
@@ -15,6 +15,7 @@ pub struct IntFmt {
     offset: Option<i128>,
     axis: Axis,
     step: i128,
+    minor: Vec<i128>,
 }
 impl IntFmt {
     pub fn step(&self) -> &i128 {
@@ -58,6 +59,9 @@ impl ticks::tick_fmt::TickFmt<i128> for IntFmt {
             Ok(())
         }
     }
+    fn minor_ticks(&mut self) -> Vec<i128> {
+        self.minor.clone()
+    }
 }
 
 impl TickDistGen<i128> for IntegerTickFmt {
@@ -74,6 +78,7 @@ impl TickDistGen<i128> for IntegerTickFmt {
         let tick_layout = TickLayout::new(&[1, 2, 5], ideal_num_steps, range);
 
         let (offset, ticks) = tick_layout.generate();
+        let minor = tick_layout.generate_minor(range);
 
         let dash_size = compute_best_dash_1_2_5(
             tick_layout.step.scale(range, canvas.max),
@@ -92,6 +97,7 @@ impl TickDistGen<i128> for IntegerTickFmt {
                 offset,
                 axis,
                 step: tick_layout.step,
+                minor,
             },
         }
         // IntegerTickFmt {
@@ -191,6 +197,38 @@ impl TickLayout {
         (display_relative, ticks)
     }
 
+    ///
+    /// Subdivide each step into the most parts that still land on whole numbers.
+    /// Only the positions inside of the range that don't land on a major tick are returned.
+    ///
+    fn generate_minor(&self, range: [i128; 2]) -> Vec<i128> {
+        let candidates: &[i128] = if self.normalized_step == 2 {
+            &[4, 2]
+        } else {
+            &[5, 2]
+        };
+
+        let parts = match candidates.iter().find(|&&p| self.step % p == 0) {
+            Some(&p) => p,
+            None => return Vec::new(),
+        };
+
+        let minor_step = self.step / parts;
+        let start = self.start_tick - self.step;
+
+        let mut ticks = Vec::new();
+        for a in 1i128.. {
+            let position = start + minor_step * a;
+            if position > range[1] {
+                break;
+            }
+            if a % parts != 0 && position >= range[0] {
+                ticks.push(position);
+            }
+        }
+        ticks
+    }
+
     fn new(good_steps: &[u32], ideal_num_steps: u32, range_all: [i128; 2]) -> TickLayout {
         let ideal_num_steps = ideal_num_steps.max(2);
 
@@ -61,6 +61,7 @@ pub struct UnixTimeFmt<T: TimeZone + Display> {
     start: UnixTime,
     footnote: Option<char>,
     index: usize,
+    minor: Vec<UnixTime>,
 }
 impl<T: TimeZone + Display> UnixTimeFmt<T> {
     pub fn step(&self) -> &StepUnit {
@@ -103,6 +104,9 @@ where
         self.footnote = Some(footnote);
         Ok(())
     }
+    fn minor_ticks(&mut self) -> Vec<UnixTime> {
+        self.minor.clone()
+    }
 }
 
 impl<T: TimeZone + Display> TickDistGen<UnixTime> for UnixTimeTickFmt<T>
@@ -149,6 +153,21 @@ where
 
         assert!(ticks.len() >= 2);
 
+        let minor = match minor_step(ret.unit_data, ret.chosen_tick) {
+            Some((unit, step)) => {
+                let d = range[0].datetime(&self.timezone);
+                match unit {
+                    YR => minor_ticks(UnixYearGenerator { date: d }.generate(step), end, &ticks),
+                    MO => minor_ticks(UnixMonthGenerator { date: d }.generate(step), end, &ticks),
+                    DY => minor_ticks(UnixDayGenerator { date: d }.generate(step), end, &ticks),
+                    HR => minor_ticks(UnixHourGenerator { date: d }.generate(step), end, &ticks),
+                    MI => minor_ticks(UnixMinuteGenerator { date: d }.generate(step), end, &ticks),
+                    SE => minor_ticks(UnixSecondGenerator { date: d }.generate(step), end, &ticks),
+                }
+            }
+            None => Vec::new(),
+        };
+
         let start = ticks[0];
 
         let index = req.request();
@@ -162,11 +181,47 @@ where
                 footnote: None,
                 start,
                 index,
+                minor,
             },
         }
     }
 }
 
+///
+/// Pick the unit and step size of the minor ticks. Small steps are subdivided
+/// by the next smaller unit, e.g. a tick every month gets a minor tick every day.
+///
+fn minor_step(unit: StepUnit, step: i64) -> Option<(StepUnit, i64)> {
+    use StepUnit::*;
+    if step > 1 {
+        let step = if step <= 7 {
+            1
+        } else {
+            step / [5, 4, 2].into_iter().find(|&p| step % p == 0)?
+        };
+        return Some((unit, step));
+    }
+
+    match unit {
+        YR => Some((MO, 1)),
+        MO => Some((DY, 1)),
+        DY => Some((HR, 6)),
+        HR => Some((MI, 15)),
+        MI => Some((SE, 15)),
+        SE => None,
+    }
+}
+
+fn minor_ticks<I: Iterator<Item = UnixTime>>(
+    it: I,
+    end: UnixTime,
+    major: &[UnixTime],
+) -> Vec<UnixTime> {
+    it.take_while(|&a| a <= end)
+        .filter(|a| !major.contains(a))
+        .collect()
+}
+
 ///
 /// Conveys what unit is being used for step sizes.
 ///
@@ -30,7 +30,7 @@ fn test_leap_day() {
     .nth(10)
     .unwrap();
 
-    let exp = vt.ymd(2025, 09, 01).into();
+    let exp = vt.ymd(2025, 9, 1).into();
 
     assert_eq!(t, exp);
 }
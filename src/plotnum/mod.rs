@@ -44,10 +44,27 @@ use std::fmt;
 ///
 /// Group methods to write title/xname/yname
 ///
+/// Newlines start a new line of text.
+///
 pub trait BaseFmt {
     fn write_title(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result;
     fn write_xname(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result;
     fn write_yname(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result;
+
+    ///
+    /// Text drawn under the title. Nothing is drawn if nothing is written.
+    ///
+    fn write_subtitle(&mut self, _writer: &mut dyn fmt::Write) -> fmt::Result {
+        Ok(())
+    }
+
+    ///
+    /// Text drawn in the bottom left corner, such as the source of the data.
+    /// Nothing is drawn if nothing is written.
+    ///
+    fn write_caption(&mut self, _writer: &mut dyn fmt::Write) -> fmt::Result {
+        Ok(())
+    }
 }
 
 ///
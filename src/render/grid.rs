@@ -0,0 +1,339 @@
+//!
+//! Lay out multiple plots in a grid inside one svg
+//!
+//! Each plot is rendered as it normally would be and then scaled down to fit in its cell.
+//! Each cell is a SVG g element that belongs to the `poloto_grid_cell` css class
+//! and is clipped to the bounds of its plot. The ids of the clip paths and of the elements
+//! of each plot start with a prefix that is different for every grid, or the one set using [`Grid::with_id`].
+//!
+//! The plots of a grid can have different types of numbers on their axes,
+//! unless the axis is shared using [`Grid::share_x`] or [`Grid::share_y`].
+//!
+use super::*;
+
+///
+/// Whether an axis is shared by all the plots of a [`Grid`], and if so, the range of all of them.
+///
+pub trait GridAxis<N> {
+    ///
+    /// Add the range of a plot.
+    ///
+    fn add(&mut self, bound: DataBound<N>);
+
+    ///
+    /// The range to draw every plot with, or `None` to keep the plot's own range.
+    ///
+    fn bound(&self) -> Option<DataBound<N>>;
+}
+
+///
+/// An axis that every plot of a [`Grid`] draws with its own range.
+///
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Independent;
+
+impl<N> GridAxis<N> for Independent {
+    fn add(&mut self, _: DataBound<N>) {}
+    fn bound(&self) -> Option<DataBound<N>> {
+        None
+    }
+}
+
+///
+/// An axis that all the plots of a [`Grid`] share, so they all need the same type of number on it.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Shared<N>(Option<DataBound<N>>);
+
+impl<N: PlotNum> GridAxis<N> for Shared<N> {
+    fn add(&mut self, b: DataBound<N>) {
+        self.0 = Some(match self.0 {
+            Some(a) => DataBound {
+                min: if b.min < a.min { b.min } else { a.min },
+                max: if b.max > a.max { b.max } else { a.max },
+            },
+            None => b,
+        });
+    }
+    fn bound(&self) -> Option<DataBound<N>> {
+        self.0
+    }
+}
+
+type CellRender<'a, SX, SY> =
+    Box<dyn FnOnce(&SX, &SY, [bool; 2], &str) -> Result<elem::BufferedElem, fmt::Error> + 'a>;
+
+struct Cell<'a, SX, SY> {
+    dim: [f64; 2],
+    render: CellRender<'a, SX, SY>,
+}
+
+///
+/// A grid of plots. Create one using [`render::grid`](super::grid).
+///
+/// ```
+/// use poloto::build::plot;
+/// let a = poloto::data(poloto::plots!(plot("a").line().buffered([[0.0, 1.0], [1.0, 3.0]].iter())));
+/// let b = poloto::data(poloto::plots!(plot("b").line().buffered([[0.0, 2.0], [2.0, 1.0]].iter())));
+///
+/// let svg = poloto::render::grid(1, 2)
+///     .share_y()
+///     .add(a, ("a", "x", "y"))
+///     .add(b, ("b", "x", "y"))
+///     .label("dashboard")
+///     .append_to(poloto::header().light_theme())
+///     .render_string();
+/// ```
+///
+pub struct Grid<'a, SX = Independent, SY = Independent> {
+    rows: usize,
+    cols: usize,
+    dim: Option<[f64; 2]>,
+    share_x: SX,
+    share_y: SY,
+    //Whether the x and y axes are shared.
+    shared: [bool; 2],
+    id: Option<String>,
+    cells: Vec<Cell<'a, SX, SY>>,
+}
+
+impl<'a> Grid<'a> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        assert!(
+            rows > 0 && cols > 0,
+            "A grid needs at least one row and column"
+        );
+        Grid {
+            rows,
+            cols,
+            dim: None,
+            share_x: Independent,
+            share_y: Independent,
+            shared: [false, false],
+            id: None,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<'a, SY> Grid<'a, Independent, SY> {
+    ///
+    /// Give every plot the same x range. Only the plots at the bottom of each column
+    /// have their x tick labels and x name drawn.
+    ///
+    /// All the plots need the same type of number on the x axis. Call this before adding plots.
+    ///
+    pub fn share_x<X: PlotNum>(self) -> Grid<'a, Shared<X>, SY> {
+        assert!(
+            self.cells.is_empty(),
+            "Share the axes of a grid before adding plots"
+        );
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            dim: self.dim,
+            share_x: Shared(None),
+            share_y: self.share_y,
+            shared: [true, self.shared[1]],
+            id: self.id,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<'a, SX> Grid<'a, SX, Independent> {
+    ///
+    /// Give every plot the same y range. Only the plots in the first column
+    /// have their y tick labels and y name drawn.
+    ///
+    /// All the plots need the same type of number on the y axis. Call this before adding plots.
+    ///
+    pub fn share_y<Y: PlotNum>(self) -> Grid<'a, SX, Shared<Y>> {
+        assert!(
+            self.cells.is_empty(),
+            "Share the axes of a grid before adding plots"
+        );
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            dim: self.dim,
+            share_x: self.share_x,
+            share_y: Shared(None),
+            shared: [self.shared[0], true],
+            id: self.id,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<'a, SX, SY> Grid<'a, SX, SY> {
+    ///
+    /// The size of the whole grid. This should match the viewbox of the header.
+    /// Defaults to the default header viewbox.
+    ///
+    pub fn with_viewbox(mut self, dim: [f64; 2]) -> Self {
+        self.dim = Some(dim);
+        self
+    }
+
+    ///
+    /// The prefix of the ids of the elements in the grid.
+    /// Use this to get the same svg every time, as long as the prefix is unique in the document.
+    ///
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    ///
+    /// Add a plot to the next free cell. Cells are filled one row at a time.
+    ///
+    pub fn add<P, TX, TY, L>(mut self, plot: Stage1<P, TX, TY>, labels: L) -> Self
+    where
+        P: PlotIterator + 'a,
+        TX: TickDistGen<P::X> + 'a,
+        TY: TickDistGen<P::Y> + 'a,
+        L: BaseFmt + 'a,
+        SX: GridAxis<P::X>,
+        SY: GridAxis<P::Y>,
+    {
+        assert!(
+            self.cells.len() < self.rows * self.cols,
+            "The grid is already full"
+        );
+
+        let dim = plot.opt.dim.unwrap_or_else(|| Header::new().get_viewbox());
+
+        self.share_x.add(plot.boundx);
+        self.share_y.add(plot.boundy);
+
+        self.cells.push(Cell {
+            dim,
+            render: Box::new(move |share_x: &SX, share_y: &SY, [hide_x, hide_y], id| {
+                let mut plot = plot;
+                if let Some(boundx) = share_x.bound() {
+                    plot.boundx = boundx;
+                }
+                if let Some(boundy) = share_y.bound() {
+                    plot.boundy = boundy;
+                }
+                plot.opt.hide_xlabels = hide_x;
+                plot.opt.hide_ylabels = hide_y;
+                elem::BufferedElem::new(plot.build().label(labels).with_id(id))
+            }),
+        });
+        self
+    }
+
+    pub fn label<T: Display>(self, title: T) -> GridLabeled<'a, SX, SY, T> {
+        GridLabeled { grid: self, title }
+    }
+}
+
+///
+/// A grid of plots with a shared title, ready to be rendered.
+///
+pub struct GridLabeled<'a, SX, SY, T> {
+    grid: Grid<'a, SX, SY>,
+    title: T,
+}
+
+impl<'a, SX, SY, T: Display> GridLabeled<'a, SX, SY, T> {
+    pub fn append_to<E: Elem>(self, elem: E) -> Stage4<elem::Append<E, Self>> {
+        Stage4(elem.append(self))
+    }
+
+    pub fn headless(self) -> Stage4<Self> {
+        Stage4(self)
+    }
+}
+
+impl<'a, SX, SY, T> Locked for GridLabeled<'a, SX, SY, T> {}
+
+impl<'a, SX, SY, T: Display> Elem for GridLabeled<'a, SX, SY, T> {
+    type Tail = ();
+    fn render_head(self, writer: &mut elem::ElemWrite) -> Result<Self::Tail, fmt::Error> {
+        let GridLabeled {
+            grid:
+                Grid {
+                    rows,
+                    cols,
+                    dim,
+                    share_x,
+                    share_y,
+                    shared: [shared_x, shared_y],
+                    id,
+                    cells,
+                },
+            title,
+        } = self;
+
+        let [width, height] = dim.unwrap_or_else(|| Header::new().get_viewbox());
+
+        let prefix = id.unwrap_or_else(|| unique_id("grid"));
+
+        writer.render(
+            hbuild::single("circle").with(attrs!(("r", "1e5"), ("class", "poloto_background"))),
+        )?;
+
+        let title_height = height / 10.0;
+
+        writer.render(
+            hbuild::elem("text")
+                .with(attrs!(
+                    ("class", "poloto_labels poloto_text poloto_title"),
+                    ("x", width / 2.0),
+                    ("y", title_height * 0.75)
+                ))
+                .append(title),
+        )?;
+
+        let cell_width = width / (cols as f64);
+        let cell_height = (height - title_height) / (rows as f64);
+
+        let num = cells.len();
+        for (i, cell) in cells.into_iter().enumerate() {
+            let (row, col) = (i / cols, i % cols);
+
+            let hide_x = shared_x && i + cols < num;
+            let hide_y = shared_y && col != 0;
+
+            let [w, h] = cell.dim;
+            let scale = (cell_width / w).min(cell_height / h);
+
+            //Center the plot in its cell.
+            let x = cell_width * (col as f64) + (cell_width - w * scale) / 2.0;
+            let y = title_height + cell_height * (row as f64) + (cell_height - h * scale) / 2.0;
+
+            let id = format!("{}_clip{}", prefix, i);
+
+            writer.render(
+                hbuild::elem("defs").append(
+                    hbuild::elem("clipPath")
+                        .with(("id", id.as_str()))
+                        .append(hbuild::single("rect").with(attrs!(("width", w), ("height", h)))),
+                ),
+            )?;
+
+            let svg = (cell.render)(
+                &share_x,
+                &share_y,
+                [hide_x, hide_y],
+                &format!("{}_cell{}", prefix, i),
+            )?;
+
+            let g = hbuild::elem("g").with(attrs!(
+                ("class", "poloto_grid_cell"),
+                (
+                    "transform",
+                    format_move!("translate({} {}) scale({})", x, y, scale)
+                ),
+                ("clip-path", format_move!("url(#{})", id))
+            ));
+
+            writer.render(g.append(&svg))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+//!
+//! Write a plot as a complete html page that needs no other files.
+//!
+//! The svg is written inline, along with the theme css that was appended to its header.
+//! A small script can be embedded that adds hover tooltips, a crosshair with the values under the
+//! cursor, and legend entries that hide and show their plot when clicked. The values are read from
+//! the numeric tick labels, which belong to the `poloto_xtick_labels` and `poloto_ytick_labels` css
+//! classes, so axes with other labels, such as dates, show the nearest tick label.
+//!
+//! ```
+//! let data = poloto::data(poloto::build::plot("sin").line().buffered(
+//!     (0..50).map(|i| [i as f64, (i as f64 / 8.0).sin()]),
+//! ));
+//!
+//! let page = data
+//!     .build_and_label(("sine", "x", "y"))
+//!     .append_to(poloto::header().light_theme())
+//!     .render_html_string(&poloto::render::html::HtmlOptions::new().with_tooltips(true))
+//!     .unwrap();
+//!
+//! assert!(page.starts_with("<!DOCTYPE html>"));
+//! ```
+//!
+use super::*;
+
+///
+/// Options for [`Stage4::render_html`].
+///
+#[derive(Clone, Debug, Default)]
+pub struct HtmlOptions {
+    title: Option<String>,
+    tooltips: bool,
+    crosshair: bool,
+    legend_toggle: bool,
+}
+
+impl HtmlOptions {
+    ///
+    /// A page without a title or script.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// The title of the page, shown in the browser tab.
+    ///
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    ///
+    /// Show the name and values of the point nearest to the cursor.
+    ///
+    pub fn with_tooltips(mut self, a: bool) -> Self {
+        self.tooltips = a;
+        self
+    }
+
+    ///
+    /// Draw lines through the cursor and show the values under it in the top left corner.
+    ///
+    pub fn with_crosshair(mut self, a: bool) -> Self {
+        self.crosshair = a;
+        self
+    }
+
+    ///
+    /// Hide or show a plot by clicking its legend entry.
+    ///
+    pub fn with_legend_toggle(mut self, a: bool) -> Self {
+        self.legend_toggle = a;
+        self
+    }
+
+    ///
+    /// Turn on all the interactive features.
+    ///
+    pub fn interactive(self) -> Self {
+        self.with_tooltips(true)
+            .with_crosshair(true)
+            .with_legend_toggle(true)
+    }
+
+    fn script(&self) -> bool {
+        self.tooltips || self.crosshair || self.legend_toggle
+    }
+}
+
+const PAGE_CSS: &str = ".poloto_html{position:relative;display:inline-block;max-width:100%;}\
+.poloto_html>svg{display:block;max-width:100%;height:auto;}\
+.poloto_html_tooltip,.poloto_html_readout{position:absolute;display:none;pointer-events:none;\
+padding:4px 8px;border-radius:4px;font:14px sans-serif;white-space:nowrap;\
+background:rgba(0,0,0,0.75);color:white;}\
+.poloto_html_readout{left:8px;top:8px;}\
+.poloto_html_crosshair{stroke:gray;stroke-width:1;stroke-dasharray:4 4;pointer-events:none;}";
+
+//Plots are found by their `poloto[N]stroke` and `poloto[N]fill` classes.
+//Screen positions are converted to values using the tick labels.
+const SCRIPT: &str = r#"document.querySelectorAll(".poloto_html").forEach(function(root){
+var svg=root.querySelector("svg");
+if(!svg)return;
+var NS="http://www.w3.org/2000/svg";
+function cls(el){return el.getAttribute("class")||"";}
+function series(el){var m=cls(el).match(/\bpoloto(\d+)(stroke|fill)\b/);return m?+m[1]:null;}
+function has(el,c){return (" "+cls(el)+" ").indexOf(" "+c+" ")>=0;}
+var area=null;
+svg.querySelectorAll(".poloto_axis_lines").forEach(function(p){
+var n=(p.getAttribute("d")||"").match(/-?[\d.]+/g);
+if(!n||n.length!=4)return;
+n=n.map(Number);
+area=area||{x:[-1/0,1/0],y:[-1/0,1/0]};
+if(n[1]==n[3])area.x=[Math.min(n[0],n[2]),Math.max(n[0],n[2])];
+if(n[0]==n[2])area.y=[Math.min(n[1],n[3]),Math.max(n[1],n[3])];
+});
+function inside(p){return !area||(p.x>=area.x[0]-1&&p.x<=area.x[1]+1&&p.y>=area.y[0]-1&&p.y<=area.y[1]+1);}
+var names={},legend={},plots={};
+svg.querySelectorAll(".poloto_legend_icon").forEach(function(icon){
+var n=series(icon);
+if(n===null)return;
+legend[n]=[icon];
+var t=icon.previousElementSibling;
+if(t&&has(t,"poloto_legend_text")){names[n]=t.textContent;legend[n].push(t);}
+});
+var pts=[];
+svg.querySelectorAll("*").forEach(function(el){
+var n=series(el);
+if(n===null||has(el,"poloto_legend_icon")||(el.parentNode&&series(el.parentNode)!==null))return;
+(plots[n]=plots[n]||[]).push(el);
+function add(x,y){var p={x:x,y:y,n:n};if(inside(p))pts.push(p);}
+if(el.tagName=="path"){
+var re=/([MLml])\s*(-?[\d.]+)[\s,]+(-?[\d.]+)/g,m;
+while((m=re.exec(el.getAttribute("d")||"")))if(m[1]=="M"||m[1]=="L")add(+m[2],+m[3]);
+}else if(el.tagName=="g"){
+el.querySelectorAll("rect").forEach(function(r){
+add(+r.getAttribute("x")+r.getAttribute("width")/2,+r.getAttribute("y"));
+});
+}
+});
+function ticks(cls,coord){
+var t=[];
+svg.querySelectorAll("."+cls).forEach(function(el){
+var s=el.textContent.trim();
+if(s)t.push({p:+el.getAttribute(coord),s:s,v:Number(s)});
+});
+return t;
+}
+var xt=ticks("poloto_xtick_labels","x");
+var yt=ticks("poloto_ytick_labels","y");
+function value(t,p){
+var num=t.filter(function(a){return isFinite(a.v);});
+if(num.length>=2){
+var a=num[0],b=num[num.length-1];
+if(a.p!=b.p){
+var step=Math.abs(b.v-a.v)/(num.length-1);
+var digits=Math.max(0,Math.min(10,1-Math.floor(Math.log10(step||1))));
+return (a.v+(p-a.p)*(b.v-a.v)/(b.p-a.p)).toFixed(digits);
+}
+}
+if(!t.length)return "";
+var best=t[0];
+t.forEach(function(a){if(Math.abs(a.p-p)<Math.abs(best.p-p))best=a;});
+return "~"+best.s;
+}
+function div(c){var d=document.createElement("div");d.className=c;root.appendChild(d);return d;}
+function toSvg(e){
+var p=svg.createSVGPoint();p.x=e.clientX;p.y=e.clientY;
+return p.matrixTransform(svg.getScreenCTM().inverse());
+}
+var hidden={};
+var tip=opt.tooltips&&div("poloto_html_tooltip");
+var readout=opt.crosshair&&div("poloto_html_readout");
+var lines=opt.crosshair&&[0,1].map(function(){
+var l=document.createElementNS(NS,"line");
+l.setAttribute("class","poloto_html_crosshair");
+l.style.display="none";
+svg.appendChild(l);
+return l;
+});
+svg.addEventListener("mousemove",function(e){
+var p=toSvg(e);
+var r=root.getBoundingClientRect();
+if(tip){
+var best=null,dist=400;
+pts.forEach(function(a){
+var d=(a.x-p.x)*(a.x-p.x)+(a.y-p.y)*(a.y-p.y);
+if(!hidden[a.n]&&d<dist){dist=d;best=a;}
+});
+if(best){
+tip.textContent=(names[best.n]?names[best.n]+": ":"")+value(xt,best.x)+", "+value(yt,best.y);
+tip.style.left=(e.clientX-r.left+12)+"px";
+tip.style.top=(e.clientY-r.top+12)+"px";
+tip.style.display="block";
+}else tip.style.display="none";
+}
+if(lines){
+var vb=svg.viewBox.baseVal;
+var ax=area?area.x:[vb.x,vb.x+vb.width],ay=area?area.y:[vb.y,vb.y+vb.height];
+var on=inside(p);
+lines.forEach(function(l,i){
+l.style.display=on?"":"none";
+l.setAttribute("x1",i?ax[0]:p.x);l.setAttribute("x2",i?ax[1]:p.x);
+l.setAttribute("y1",i?p.y:ay[0]);l.setAttribute("y2",i?p.y:ay[1]);
+});
+readout.textContent="x: "+value(xt,p.x)+"  y: "+value(yt,p.y);
+readout.style.display=on?"block":"none";
+}
+});
+svg.addEventListener("mouseleave",function(){
+if(tip)tip.style.display="none";
+if(lines){lines.forEach(function(l){l.style.display="none";});readout.style.display="none";}
+});
+if(opt.legend_toggle)Object.keys(legend).forEach(function(n){
+legend[n].forEach(function(el){
+el.style.cursor="pointer";
+el.addEventListener("click",function(){
+hidden[n]=!hidden[n];
+(plots[n]||[]).forEach(function(p){p.style.display=hidden[n]?"none":"";});
+legend[n].forEach(function(l){l.style.opacity=hidden[n]?0.3:"";});
+});
+});
+});
+});"#;
+
+//Escape text written into html.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl<R: Elem + Locked> Stage4<R> {
+    ///
+    /// Write a html page with the svg inline. See the [`html`](crate::render::html) module.
+    ///
+    /// The svg needs a header, for example by using [`Stage3::append_to`] with [`crate::header`].
+    ///
+    pub fn render_html<T: fmt::Write>(self, opt: &HtmlOptions, mut w: T) -> fmt::Result {
+        writeln!(w, "<!DOCTYPE html>")?;
+        writeln!(w, "<html>")?;
+        writeln!(w, "<head>")?;
+        writeln!(w, "<meta charset=\"utf-8\">")?;
+        writeln!(
+            w,
+            "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">"
+        )?;
+        if let Some(title) = &opt.title {
+            writeln!(w, "<title>{}</title>", escape(title))?;
+        }
+        writeln!(w, "<style>{}</style>", PAGE_CSS)?;
+        writeln!(w, "</head>")?;
+        writeln!(w, "<body>")?;
+        writeln!(w, "<div class=\"poloto_html\">")?;
+        self.render_fmt_write(&mut w)?;
+        writeln!(w)?;
+        writeln!(w, "</div>")?;
+        if opt.script() {
+            writeln!(w, "<script>")?;
+            writeln!(
+                w,
+                "(function(){{var opt={{tooltips:{},crosshair:{},legend_toggle:{}}};",
+                opt.tooltips, opt.crosshair, opt.legend_toggle
+            )?;
+            writeln!(w, "{}", SCRIPT)?;
+            writeln!(w, "}})();")?;
+            writeln!(w, "</script>")?;
+        }
+        writeln!(w, "</body>")?;
+        writeln!(w, "</html>")
+    }
+
+    ///
+    /// Write a html page into a string. See [`Stage4::render_html`].
+    ///
+    pub fn render_html_string(self, opt: &HtmlOptions) -> Result<String, fmt::Error> {
+        let mut s = String::new();
+        self.render_html(opt, &mut s)?;
+        Ok(s)
+    }
+}
@@ -0,0 +1,233 @@
+//!
+//! Embed a smaller plot inside of another plot
+//!
+//! The inset is rendered as it normally would be and then scaled down to fit inside
+//! a rectangle of the parent plot. It is a SVG g element that belongs to the `poloto_inset` css class.
+//! The id of its clip path starts with a prefix that is different for every inset,
+//! or the one set using [`Inset::with_id`].
+//!
+use super::*;
+
+///
+/// A plot to be drawn inside of another plot. Create one using [`inset`] and add it using [`Stage3::inset`].
+///
+pub struct Inset<X, Y> {
+    plot: Result<elem::BufferedElem, fmt::Error>,
+    dim: [f64; 2],
+    rect: Option<[f64; 4]>,
+    region: Option<([X; 2], [Y; 2])>,
+    id: Option<String>,
+}
+
+///
+/// Create an inset from a labeled plot. By default, it is placed in the top right corner of the parent plot.
+///
+/// ```
+/// use poloto::build::plot;
+/// let data: Vec<_> = (0..100).map(|i| [i as f64, (i as f64 / 10.0).sin()]).collect();
+///
+/// let zoom = poloto::data(poloto::plots!(plot("").line().buffered(data[20..40].iter())))
+///     .build_and_label(("", "", ""));
+///
+/// let svg = poloto::data(poloto::plots!(plot("").line().buffered(data.iter())))
+///     .build_and_label(("wave", "x", "y"))
+///     .inset(poloto::render::inset::inset(zoom).magnify([20.0, 39.0], [-1.0, 1.0]))
+///     .append_to(poloto::header().light_theme())
+///     .render_string();
+/// ```
+///
+pub fn inset<P, A, B, BB>(plot: Stage3<P, A, B, BB>) -> Inset<P::X, P::Y>
+where
+    P: PlotIterator,
+    A: crate::ticks::TickDist<Num = P::X>,
+    B: crate::ticks::TickDist<Num = P::Y>,
+    BB: BaseFmt,
+{
+    let dim = [plot.data.opt.width, plot.data.opt.height];
+    Inset {
+        plot: elem::BufferedElem::new(plot),
+        dim,
+        rect: None,
+        region: None,
+        id: None,
+    }
+}
+
+impl<X: PlotNum, Y: PlotNum> Inset<X, Y> {
+    ///
+    /// Place the inset inside of the `[x, y, width, height]` rectangle of the parent's viewbox.
+    /// The aspect ratio of the inset is preserved.
+    ///
+    pub fn at(mut self, rect: [f64; 4]) -> Self {
+        self.rect = Some(rect);
+        self
+    }
+
+    ///
+    /// Outline the `[xmin, xmax]` by `[ymin, ymax]` region of the parent plot that the inset magnifies,
+    /// and draw lines connecting it to the inset.
+    ///
+    /// The outline belongs to the `poloto_inset_region` css class and the lines belong to the
+    /// `poloto_inset_connector` css class.
+    ///
+    pub fn magnify(mut self, x: [X; 2], y: [Y; 2]) -> Self {
+        self.region = Some((x, y));
+        self
+    }
+
+    ///
+    /// The prefix of the ids of the elements of the inset.
+    /// Use this to get the same svg every time, as long as the prefix is unique in the document.
+    /// The plot inside of the inset has its own prefix, see [`Stage3::with_id`].
+    ///
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+}
+
+impl<P, A, B, BB> Stage3<P, A, B, BB>
+where
+    P: PlotIterator,
+    A: crate::ticks::TickDist<Num = P::X>,
+    B: crate::ticks::TickDist<Num = P::Y>,
+    BB: BaseFmt,
+{
+    ///
+    /// Draw another plot inside of this one.
+    ///
+    pub fn inset(mut self, inset: Inset<P::X, P::Y>) -> Self {
+        self.insets.push(inset);
+        self
+    }
+}
+
+pub(super) fn render_insets<X: PlotNum, Y: PlotNum>(
+    writer: &mut elem::ElemWrite,
+    insets: Vec<Inset<X, Y>>,
+    boundx: &DataBound<X>,
+    boundy: &DataBound<Y>,
+    canvas: &RenderOptionsResult,
+) -> fmt::Result {
+    let RenderOptionsResult {
+        height,
+        margin_bottom,
+        margin_left,
+        xaspect_offset,
+        yaspect_offset,
+        precision,
+        ..
+    } = *canvas;
+
+    let ffmt = FloatFmt::new(precision);
+
+    let scalex = canvas.boundx.max;
+    let scaley = canvas.boundy.max;
+    let left = margin_left + xaspect_offset;
+    let bottom = height - margin_bottom + yaspect_offset;
+
+    let rangex = [boundx.min, boundx.max];
+    let rangey = [boundy.min, boundy.max];
+    let to_screen = |x: X, y: Y| {
+        [
+            left + x.scale(rangex, scalex) - boundx.min.scale(rangex, scalex),
+            bottom - y.scale(rangey, scaley) + boundy.min.scale(rangey, scaley),
+        ]
+    };
+
+    for inset in insets {
+        let plot = inset.plot?;
+        let [w, h] = inset.dim;
+
+        let [rx, ry, rw, rh] = inset.rect.unwrap_or([
+            left + scalex * 0.55,
+            bottom - scaley * 0.95,
+            scalex * 0.4,
+            scaley * 0.4,
+        ]);
+
+        let scale = (rw / w).min(rh / h);
+        let (iw, ih) = (w * scale, h * scale);
+
+        //Keep the inset against the top right corner of the rectangle.
+        let [ix, iy] = [rx + rw - iw, ry];
+
+        if let Some(([x0, x1], [y0, y1])) = inset.region {
+            let [ax, ay] = to_screen(x0, y0);
+            let [bx, by] = to_screen(x1, y1);
+            let (x0, x1) = (ax.min(bx), ax.max(bx));
+            let (y0, y1) = (ay.min(by), ay.max(by));
+
+            writer.render(hbuild::single("rect").with(attrs!(
+                ("class", "poloto_inset_region"),
+                ("stroke", "black"),
+                ("fill", "none"),
+                ("x", ffmt.disp(x0)),
+                ("y", ffmt.disp(y0)),
+                ("width", ffmt.disp(x1 - x0)),
+                ("height", ffmt.disp(y1 - y0))
+            )))?;
+
+            //Connect the sides of the region and the inset that face each other.
+            let dx = (ix + iw / 2.0) - (x0 + x1) / 2.0;
+            let dy = (iy + ih / 2.0) - (y0 + y1) / 2.0;
+            let lines = if dx.abs() * (y1 - y0 + ih) >= dy.abs() * (x1 - x0 + iw) {
+                let (rx, ix) = if dx > 0.0 { (x1, ix) } else { (x0, ix + iw) };
+                [[rx, y0, ix, iy], [rx, y1, ix, iy + ih]]
+            } else {
+                let (ry, iy) = if dy > 0.0 { (y1, iy) } else { (y0, iy + ih) };
+                [[x0, ry, ix, iy], [x1, ry, ix + iw, iy]]
+            };
+
+            for [x1, y1, x2, y2] in lines {
+                writer.render(hbuild::single("line").with(attrs!(
+                    ("class", "poloto_inset_connector"),
+                    ("stroke", "black"),
+                    ("x1", ffmt.disp(x1)),
+                    ("y1", ffmt.disp(y1)),
+                    ("x2", ffmt.disp(x2)),
+                    ("y2", ffmt.disp(y2))
+                )))?;
+            }
+        }
+
+        let prefix = inset.id.unwrap_or_else(|| unique_id("inset"));
+        let id = format!("{}_clip", prefix);
+
+        writer.render(
+            hbuild::elem("defs").append(
+                hbuild::elem("clipPath")
+                    .with(("id", id.as_str()))
+                    .append(hbuild::single("rect").with(attrs!(("width", w), ("height", h)))),
+            ),
+        )?;
+
+        let g = hbuild::elem("g").with(attrs!(
+            ("class", "poloto_inset"),
+            (
+                "transform",
+                format_move!(
+                    "translate({} {}) scale({})",
+                    ffmt.disp(ix),
+                    ffmt.disp(iy),
+                    scale
+                )
+            ),
+            ("clip-path", format_move!("url(#{})", id))
+        ));
+
+        writer.render(g.append(&plot))?;
+
+        writer.render(hbuild::single("rect").with(attrs!(
+            ("class", "poloto_inset_border"),
+            ("stroke", "black"),
+            ("fill", "none"),
+            ("x", ffmt.disp(ix)),
+            ("y", ffmt.disp(iy)),
+            ("width", ffmt.disp(iw)),
+            ("height", ffmt.disp(ih))
+        )))?;
+    }
+
+    Ok(())
+}
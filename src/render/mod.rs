@@ -4,8 +4,67 @@
 
 use super::*;
 use crate::build::PlotIterator;
+pub mod grid;
+pub mod html;
+pub mod inset;
+#[cfg(feature = "png")]
+pub mod png;
 mod render_base;
 mod render_plot;
+pub mod term;
+pub mod theme;
+pub mod tikz;
+pub(crate) use render_plot::FloatFmt;
+
+///
+/// A corner of the plot area.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+///
+/// How to lay out the x tick labels. Set it using [`RenderOptions::with_xtick_labels`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TickLabels {
+    /// Draw every label horizontally. This is the default.
+    Horizontal,
+    /// Rotate every label by the specified number of degrees, such as `-45.0` or `-90.0`.
+    /// Increase the bottom margin to make room for long labels.
+    Rotate(f64),
+    /// Alternate the labels between two rows.
+    Stagger,
+    /// Only label every nth tick, where n is picked so that labels don't overlap.
+    Thin,
+    /// Estimate the width of the labels and draw them horizontally if they fit.
+    /// Otherwise rotate them if there is room in the bottom margin, stagger them, or thin them out.
+    Auto,
+}
+
+///
+/// Where to draw the legend. Set it using [`RenderOptions::with_legend`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Legend {
+    /// A column in the right margin. This is the default.
+    Right,
+    /// Rows above the plot area with the specified number of columns.
+    /// Space is reserved for as many rows as the named plots need.
+    Top(usize),
+    /// Rows below the plot area with the specified number of columns.
+    /// Space is reserved for as many rows as the named plots need.
+    Bottom(usize),
+    /// A column inside the plot area in the specified corner, drawn over a box
+    /// that belongs to the `poloto_legend_box` css class.
+    Inside(Corner),
+    /// Don't draw a legend. The space it would have used is given to the plot area.
+    Hidden,
+}
 
 ///
 /// Specify options for the svg plots
@@ -19,6 +78,18 @@ pub struct RenderOptions {
     ytick_lines: bool,
     precision: usize,
     bar_width: f64,
+    hide_xlabels: bool,
+    hide_ylabels: bool,
+    margins: [f64; 4],
+    legend_width: f64,
+    tick_spacing: [f64; 2],
+    auto_margins: bool,
+    legend: Legend,
+    xtick_labels: TickLabels,
+    minor_ticks: [bool; 2],
+    minor_tick_lines: [bool; 2],
+    auto_markers: bool,
+    num_plots: usize,
 }
 
 impl Default for RenderOptions {
@@ -31,6 +102,18 @@ impl Default for RenderOptions {
             ytick_lines: false,
             precision: 2,
             bar_width: 20.0,
+            hide_xlabels: false,
+            hide_ylabels: false,
+            margins: [100.0, 25.0, 100.0, 150.0],
+            legend_width: 125.0,
+            tick_spacing: [80.0, 60.0],
+            auto_margins: false,
+            legend: Legend::Right,
+            xtick_labels: TickLabels::Horizontal,
+            minor_ticks: [false; 2],
+            minor_tick_lines: [false; 2],
+            auto_markers: false,
+            num_plots: 0,
         }
     }
 }
@@ -50,12 +133,42 @@ impl RenderOptions {
         self
     }
 
+    ///
+    /// Draw short unlabeled ticks at the minor tick positions of the x and y axis.
+    /// They belong to the `poloto_minor_tick` css class.
+    ///
+    pub fn with_minor_ticks(&mut self, a: [bool; 2]) -> &mut Self {
+        self.minor_ticks = a;
+        self
+    }
+
+    ///
+    /// Draw faint grid lines at the minor tick positions of the x and y axis.
+    /// They belong to the `poloto_minor_tick_line` css class.
+    ///
+    pub fn with_minor_tick_lines(&mut self, a: [bool; 2]) -> &mut Self {
+        self.minor_tick_lines = a;
+        self
+    }
+
+    ///
+    /// Give each scatter plot without a chosen marker a different marker shape,
+    /// cycling through [`MarkerShape::ALL`](crate::build::style::MarkerShape::ALL).
+    ///
+    pub fn with_auto_markers(&mut self, a: bool) -> &mut Self {
+        self.auto_markers = a;
+        self
+    }
+
     ///
     /// The number of distinct css classes. If there are more plots than
     /// classes, then they will wrap around. The default value is 8.
     ///
     /// A value of None, means it will never wrap around.
     ///
+    /// This has to match the number of classes the theme styles. Use [`theme::ThemeBuilder::render_opt`]
+    /// to start from options that match a custom theme.
+    ///
     pub fn num_css_class(&mut self, a: Option<usize>) -> &mut Self {
         self.num_css_classes = a;
         self
@@ -86,11 +199,63 @@ impl RenderOptions {
         self
     }
 
+    ///
+    /// The space between the edges of the viewbox and the plot area as `[top, right, bottom, left]`.
+    /// The legend is placed to the right of the right margin.
+    /// Defaults to `[100.0, 25.0, 100.0, 150.0]`.
+    ///
+    pub fn with_margins(&mut self, margins: [f64; 4]) -> &mut Self {
+        self.margins = margins;
+        self
+    }
+
+    ///
+    /// The width of the legend on the right side of the plot. Defaults to 125.
+    ///
+    pub fn with_legend_width(&mut self, width: f64) -> &mut Self {
+        self.legend_width = width;
+        self
+    }
+
+    ///
+    /// The ideal distance in pixels between the x and y ticks. The actual distance depends on
+    /// what tick values look nice. Defaults to `[80.0, 60.0]`.
+    ///
+    pub fn with_tick_spacing(&mut self, spacing: [f64; 2]) -> &mut Self {
+        self.tick_spacing = spacing;
+        self
+    }
+
+    ///
+    /// Size the left margin to fit the y tick labels, estimating their width from
+    /// the number of characters in each formatted label.
+    ///
+    pub fn with_auto_margins(&mut self) -> &mut Self {
+        self.auto_margins = true;
+        self
+    }
+
+    ///
+    /// Where to draw the legend. Defaults to [`Legend::Right`].
+    ///
+    pub fn with_legend(&mut self, legend: Legend) -> &mut Self {
+        self.legend = legend;
+        self
+    }
+
+    ///
+    /// How to lay out the x tick labels. Defaults to [`TickLabels::Horizontal`].
+    ///
+    pub fn with_xtick_labels(&mut self, labels: TickLabels) -> &mut Self {
+        self.xtick_labels = labels;
+        self
+    }
+
     pub fn move_into(&mut self) -> Self {
         self.clone()
     }
 
-    fn compute(&mut self) -> RenderOptionsResult {
+    pub(crate) fn compute(&mut self) -> RenderOptionsResult {
         let (width, height) = if let Some([x, y]) = self.dim {
             (x, y)
         } else {
@@ -102,44 +267,65 @@ impl RenderOptions {
         let padding = 150.0;
         let paddingy = 100.0;
 
+        let spacing = padding / 3.0;
+
+        let [mut margin_top, margin_right, mut margin_bottom, margin_left] = self.margins;
+        let legend_width = self.legend_width;
+
+        //A legend above or below the plot area needs a row for every `columns` named plots.
+        let legend_rows = match self.legend {
+            Legend::Top(columns) | Legend::Bottom(columns) => {
+                self.num_plots.div_ceil(columns.max(1)).max(1)
+            }
+            _ => 0,
+        };
+
+        //Only a legend on the right takes up horizontal space.
+        let legend_space = match self.legend {
+            Legend::Right => legend_width,
+            Legend::Top(_) => {
+                margin_top += spacing * legend_rows as f64;
+                0.0
+            }
+            Legend::Bottom(_) => {
+                margin_bottom += spacing * legend_rows as f64;
+                0.0
+            }
+            Legend::Inside(_) | Legend::Hidden => 0.0,
+        };
+
+        let available_width = width - margin_left - margin_right - legend_space;
+        let available_height = height - margin_top - margin_bottom;
+
         //The range over which the data will be scaled to fit
         let (scalex, scaley) = if self.preserve_aspect {
-            if width > height {
-                (height - paddingy * 2.0, height - paddingy * 2.0)
-            } else {
-                (width - padding * 2.0, width - padding * 2.0)
-            }
+            let a = available_width.min(available_height);
+            (a, a)
         } else {
-            (width - padding * 2.0, height - paddingy * 2.0)
+            (available_width, available_height)
         };
 
         let distancex_min_to_max = scalex;
         let distancey_min_to_max = scaley;
 
+        //Center the plot area in the available space.
         let (xaspect_offset, yaspect_offset) = if self.preserve_aspect {
-            if width > height {
-                (-padding + width / 2.0 - distancey_min_to_max / 2.0, 0.0)
-            } else {
-                (
-                    0.0,
-                    -height + paddingy + height / 2.0 + distancey_min_to_max / 2.0,
-                )
-            }
+            (
+                (available_width - scalex) / 2.0,
+                -(available_height - scaley) / 2.0,
+            )
         } else {
             (0.0, 0.0)
         };
 
-        let ideal_xtick_spacing = 80.0;
-
-        let ideal_ytick_spacing = 60.0;
+        let [ideal_xtick_spacing, ideal_ytick_spacing] = self.tick_spacing;
 
         let ideal_num_xsteps = (distancex_min_to_max / ideal_xtick_spacing).floor() as u32;
         let ideal_num_ysteps = (distancey_min_to_max / ideal_ytick_spacing).floor() as u32;
         let ideal_num_xsteps = ideal_num_xsteps.max(2);
         let ideal_num_ysteps = ideal_num_ysteps.max(2);
 
-        let spacing = padding / 3.0;
-        let legendx1 = width - padding / 1.2 + padding / 30.0;
+        let legendx1 = width - legend_width + padding / 30.0;
 
         RenderOptionsResult {
             boundx: ticks::RenderOptionsBound {
@@ -168,6 +354,20 @@ impl RenderOptions {
             ytick_lines: self.ytick_lines,
             precision: self.precision,
             bar_width: self.bar_width,
+            polar: false,
+            hide_xlabels: self.hide_xlabels,
+            hide_ylabels: self.hide_ylabels,
+            margin_top,
+            margin_bottom,
+            margin_left,
+            legend_width,
+            auto_margins: self.auto_margins,
+            legend: self.legend,
+            legend_rows,
+            xtick_labels: self.xtick_labels,
+            minor_ticks: self.minor_ticks,
+            minor_tick_lines: self.minor_tick_lines,
+            auto_markers: self.auto_markers,
         }
     }
 }
@@ -176,28 +376,69 @@ impl RenderOptions {
 /// Contains graphical information for a svg graph.
 ///
 #[derive(Clone)]
-struct RenderOptionsResult {
-    boundx: ticks::RenderOptionsBound,
-    boundy: ticks::RenderOptionsBound,
-    width: f64,
-    height: f64,
-    padding: f64,
-    paddingy: f64,
-    xaspect_offset: f64,
-    yaspect_offset: f64,
-    spacing: f64,
-    legendx1: f64,
-    num_css_classes: Option<usize>,
-    xtick_lines: bool,
-    ytick_lines: bool,
-    precision: usize,
-    bar_width: f64,
+pub(crate) struct RenderOptionsResult {
+    pub(crate) boundx: ticks::RenderOptionsBound,
+    pub(crate) boundy: ticks::RenderOptionsBound,
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+    pub(crate) padding: f64,
+    pub(crate) paddingy: f64,
+    pub(crate) xaspect_offset: f64,
+    pub(crate) yaspect_offset: f64,
+    pub(crate) spacing: f64,
+    pub(crate) legendx1: f64,
+    pub(crate) num_css_classes: Option<usize>,
+    pub(crate) xtick_lines: bool,
+    pub(crate) ytick_lines: bool,
+    pub(crate) precision: usize,
+    pub(crate) bar_width: f64,
+    pub(crate) polar: bool,
+    pub(crate) hide_xlabels: bool,
+    pub(crate) hide_ylabels: bool,
+    pub(crate) margin_top: f64,
+    pub(crate) margin_bottom: f64,
+    pub(crate) margin_left: f64,
+    pub(crate) legend_width: f64,
+    pub(crate) auto_margins: bool,
+    pub(crate) legend: Legend,
+    pub(crate) legend_rows: usize,
+    pub(crate) xtick_labels: TickLabels,
+    pub(crate) minor_ticks: [bool; 2],
+    pub(crate) minor_tick_lines: [bool; 2],
+    pub(crate) auto_markers: bool,
+}
+
+impl RenderOptionsResult {
+    /// The center and radius of the circle that polar plots are drawn in.
+    pub(crate) fn polar_circle(&self) -> ([f64; 2], f64) {
+        let radius = self.boundx.max.min(self.boundy.max) / 2.0;
+        let cx = self.margin_left + self.xaspect_offset + self.boundx.max / 2.0;
+        let cy = self.height - self.margin_bottom + self.yaspect_offset - self.boundy.max / 2.0;
+        ([cx, cy], radius)
+    }
+}
+
+///
+/// A prefix for element ids that is different every time it is called,
+/// so that svgs embedded in the same document don't refer to each other's elements.
+///
+pub(crate) fn unique_id(name: &str) -> String {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    format!("poloto_{}{}", name, COUNTER.fetch_add(1, Ordering::Relaxed))
 }
 
 pub fn render_opt() -> RenderOptions {
     RenderOptions::default()
 }
 
+///
+/// Start a [`Grid`](grid::Grid) of plots with the specified number of rows and columns.
+///
+pub fn grid<'a>(rows: usize, cols: usize) -> grid::Grid<'a> {
+    grid::Grid::new(rows, cols)
+}
+
 ///
 /// Link some plots with a way to render them.
 ///
@@ -208,6 +449,8 @@ pub struct Stage1<P: PlotIterator, TX, TY> {
     plots: P,
     boundx: DataBound<P::X>,
     boundy: DataBound<P::Y>,
+    polar: bool,
+    num_plots: usize,
 }
 
 impl<X, Y, P: build::PlotIterator<X = X, Y = Y>> Stage1<P, X::DefaultTicks, Y::DefaultTicks>
@@ -229,6 +472,7 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
     pub fn from_parts(mut plots: P, tickx: TX, ticky: TY, opt: RenderOptions) -> Stage1<P, TX, TY> {
         let mut area = build::marker::Area::new();
         plots.increase_area(&mut area);
+        let num_plots = area.num_plots();
         let (boundx, boundy) = area.build();
 
         Stage1 {
@@ -238,6 +482,8 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
             tickx,
             boundx,
             boundy,
+            polar: false,
+            num_plots,
         }
     }
 
@@ -249,6 +495,8 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
             plots: self.plots,
             boundx: self.boundx,
             boundy: self.boundy,
+            polar: self.polar,
+            num_plots: self.num_plots,
         }
     }
 
@@ -264,6 +512,8 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
             plots: self.plots,
             boundx: self.boundx,
             boundy: self.boundy,
+            polar: self.polar,
+            num_plots: self.num_plots,
         }
     }
 
@@ -279,6 +529,34 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
             plots: self.plots,
             boundx: self.boundx,
             boundy: self.boundy,
+            polar: self.polar,
+            num_plots: self.num_plots,
+        }
+    }
+
+    ///
+    /// Render the plots using polar coordinates instead of cartesian ones.
+    /// The x values are interpreted as angles and the y values as the radius.
+    ///
+    /// `full_turn` is the range of x values that make up one full revolution,
+    /// for example `[0.0, 360.0]` for degrees. The angle starts at the right and increases counter-clockwise.
+    /// The x ticks are generated over this range and drawn around the circle,
+    /// while the y ticks are drawn as circular grid lines.
+    ///
+    /// Only line, scatter, and line fill plots are supported. Other plot types are drawn as lines.
+    ///
+    pub fn polar(self, full_turn: [P::X; 2]) -> Self {
+        let [min, max] = full_turn;
+        assert!(min < max, "The full turn range must be increasing");
+        Stage1 {
+            opt: self.opt,
+            tickx: self.tickx,
+            ticky: self.ticky,
+            plots: self.plots,
+            boundx: DataBound { min, max },
+            boundy: self.boundy,
+            polar: true,
+            num_plots: self.num_plots,
         }
     }
 
@@ -290,7 +568,9 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
     pub fn build(self) -> Stage2<P, TX::Res, TY::Res> {
         let mut index_counter = 0;
         let mut data = self;
-        let opt = data.opt.compute();
+        data.opt.num_plots = data.num_plots;
+        let mut opt = data.opt.compute();
+        opt.polar = data.polar;
 
         let xticks = data.tickx.generate(
             &data.boundx,
@@ -303,6 +583,7 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
             IndexRequester::new(&mut index_counter),
         );
         Stage2 {
+            src: data.opt,
             opt,
             xticks,
             yticks,
@@ -318,6 +599,7 @@ impl<P: build::PlotIterator, TX: TickDistGen<P::X>, TY: TickDistGen<P::Y>> Stage
 }
 
 pub struct Stage2<P: PlotIterator, A, B> {
+    src: RenderOptions,
     opt: RenderOptionsResult,
     xticks: A,
     yticks: B,
@@ -331,6 +613,8 @@ impl<P: PlotIterator, A: TickDist<Num = P::X>, B: TickDist<Num = P::Y>> Stage2<P
         Stage3 {
             data: self,
             base: fmt,
+            insets: Vec::new(),
+            id: None,
         }
     }
 
@@ -383,6 +667,8 @@ impl<P: PlotIterator, A: TickDist<Num = P::X>, B: TickDist<Num = P::Y>> Stage2<P
 pub struct Stage3<P: PlotIterator, A, B, BB> {
     data: Stage2<P, A, B>,
     base: BB,
+    insets: Vec<inset::Inset<P::X, P::Y>>,
+    id: Option<String>,
 }
 
 impl<P, A, B, BB> Stage3<P, A, B, BB>
@@ -399,6 +685,17 @@ where
     pub fn headless(self) -> Stage4<Self> {
         Stage4(self)
     }
+
+    ///
+    /// The prefix of the ids of the markers, patterns and gradients of the plot.
+    /// By default it is different for every render, so that plots in the same document
+    /// don't refer to each other's elements. Use this to get the same svg every time,
+    /// as long as the prefix is unique in the document.
+    ///
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
 }
 
 impl<P: PlotIterator, A, B, BB> Locked for Stage3<P, A, B, BB> {}
@@ -411,31 +708,113 @@ where
     BB: BaseFmt,
 {
     type Tail = ();
-    fn render_head(mut self, writer: &mut elem::ElemWrite) -> Result<Self::Tail, fmt::Error> {
+    fn render_head(self, writer: &mut elem::ElemWrite) -> Result<Self::Tail, fmt::Error> {
         writer.render(
             hbuild::single("circle").with(attrs!(("r", "1e5"), ("class", "poloto_background"))),
         )?;
 
-        render::render_plot::render_plot(
-            writer,
-            &self.data.boundx,
-            &self.data.boundy,
-            &self.data.opt,
-            &mut self.data.plots,
-        )?;
+        let Stage3 {
+            data:
+                Stage2 {
+                    src,
+                    opt,
+                    xticks,
+                    yticks,
+                    mut plots,
+                    boundx,
+                    boundy,
+                },
+            mut base,
+            insets,
+            id,
+        } = self;
+
+        let prefix = id.unwrap_or_else(|| unique_id("plot"));
+
+        let texts = render_base::Texts::new(&mut base)?;
+        let [extra_top, extra_bottom] = texts.extra_space(&opt);
+        let auto_margins = opt.auto_margins && !opt.polar;
+
+        if !auto_margins && extra_top == 0.0 && extra_bottom == 0.0 {
+            return render_stage3(
+                writer, xticks, yticks, &mut plots, &boundx, &boundy, &opt, &texts, insets, &prefix,
+            );
+        }
+
+        //The plot area has to be resized, so lay everything out again.
+        let mut xticks = xticks.unwrap();
+        let mut yticks = yticks.unwrap();
+        let ytick_vals: Vec<_> = yticks.iter.into_iter().collect();
+
+        let mut src = src;
+        let mut margins = src.margins;
+        margins[0] += extra_top;
+        margins[2] += extra_bottom;
+
+        if auto_margins {
+            //Estimate the width of the widest y tick label from its number of characters.
+            let mut max_chars = 0;
+            if !opt.hide_ylabels {
+                for val in ytick_vals
+                    .iter()
+                    .skip_while(|&&y| y < boundy.min)
+                    .take_while(|&&y| y <= boundy.max)
+                {
+                    let mut s = String::new();
+                    ticks::tick_fmt::TickFmt::write_tick(&mut yticks.fmt, &mut s, val)?;
+                    max_chars = max_chars.max(s.chars().count());
+                }
+            }
 
-        render::render_base::render_base(
-            writer,
-            self.data.xticks,
-            self.data.yticks,
-            &self.data.boundx,
-            &self.data.boundy,
-            &mut self.base,
-            &self.data.opt,
+            let text_width = max_chars as f64 * render_base::TICK_CHAR_WIDTH;
+            margins[3] = text_width + opt.padding * 0.1 + opt.padding * 0.4;
+        }
+
+        let mut new_opt = src.with_margins(margins).compute();
+        new_opt.polar = opt.polar;
+
+        //The ticks were picked for the old plot area, so scale their dashes to the new one.
+        xticks.res.dash_size = xticks
+            .res
+            .dash_size
+            .map(|d| d * new_opt.boundx.max / opt.boundx.max);
+        yticks.res.dash_size = yticks
+            .res
+            .dash_size
+            .map(|d| d * new_opt.boundy.max / opt.boundy.max);
+
+        let yticks = ticks::TickDistribution {
+            iter: ytick_vals,
+            fmt: yticks.fmt,
+            res: yticks.res,
+        };
+
+        render_stage3(
+            writer, xticks, yticks, &mut plots, &boundx, &boundy, &new_opt, &texts, insets, &prefix,
         )
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+fn render_stage3<P: PlotIterator>(
+    writer: &mut elem::ElemWrite,
+    xticks: impl TickDist<Num = P::X>,
+    yticks: impl TickDist<Num = P::Y>,
+    plots: &mut P,
+    boundx: &DataBound<P::X>,
+    boundy: &DataBound<P::Y>,
+    opt: &RenderOptionsResult,
+    texts: &render_base::Texts,
+    insets: Vec<inset::Inset<P::X, P::Y>>,
+    prefix: &str,
+) -> fmt::Result {
+    render::render_plot::render_plot(writer, boundx, boundy, opt, plots, prefix)?;
+
+    render::render_base::render_base(writer, xticks, yticks, boundx, boundy, texts, opt)?;
+
+    inset::render_insets(writer, insets, boundx, boundy, opt)
+}
+
 impl<A, B, C> BaseFmt for (A, B, C)
 where
     A: Display,
@@ -453,7 +832,82 @@ where
     }
 }
 
-pub struct Stage4<R>(R);
+///
+/// A title, axis names, and an optional subtitle and caption.
+///
+/// ```
+/// use poloto::build::plot;
+/// let labels = poloto::render::Labels::new("rainfall", "month", "mm")
+///     .with_subtitle("2021 to 2022")
+///     .with_caption("source: weather station 12\nmissing days are interpolated");
+///
+/// let svg = poloto::data(poloto::plots!(plot("").line().cloned([[1, 80], [2, 65], [3, 40]].iter())))
+///     .build_and_label(labels)
+///     .append_to(poloto::header().light_theme())
+///     .render_string();
+/// ```
+///
+pub struct Labels<T, X, Y, S, C> {
+    title: T,
+    xname: X,
+    yname: Y,
+    subtitle: S,
+    caption: C,
+}
+
+impl<T: Display, X: Display, Y: Display> Labels<T, X, Y, &'static str, &'static str> {
+    pub fn new(title: T, xname: X, yname: Y) -> Self {
+        Labels {
+            title,
+            xname,
+            yname,
+            subtitle: "",
+            caption: "",
+        }
+    }
+}
+
+impl<T: Display, X: Display, Y: Display, S: Display, C: Display> Labels<T, X, Y, S, C> {
+    pub fn with_subtitle<SS: Display>(self, subtitle: SS) -> Labels<T, X, Y, SS, C> {
+        Labels {
+            title: self.title,
+            xname: self.xname,
+            yname: self.yname,
+            subtitle,
+            caption: self.caption,
+        }
+    }
+
+    pub fn with_caption<CC: Display>(self, caption: CC) -> Labels<T, X, Y, S, CC> {
+        Labels {
+            title: self.title,
+            xname: self.xname,
+            yname: self.yname,
+            subtitle: self.subtitle,
+            caption,
+        }
+    }
+}
+
+impl<T: Display, X: Display, Y: Display, S: Display, C: Display> BaseFmt for Labels<T, X, Y, S, C> {
+    fn write_title(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        write!(writer, "{}", self.title)
+    }
+    fn write_xname(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        write!(writer, "{}", self.xname)
+    }
+    fn write_yname(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        write!(writer, "{}", self.yname)
+    }
+    fn write_subtitle(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        write!(writer, "{}", self.subtitle)
+    }
+    fn write_caption(&mut self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        write!(writer, "{}", self.caption)
+    }
+}
+
+pub struct Stage4<R>(pub(crate) R);
 impl<R: Elem + Locked> Stage4<R> {
     pub fn render_stdout(self) {
         hypermelon::render(self.0, hypermelon::stdout_fmt()).unwrap()
@@ -559,7 +1013,14 @@ impl<A: Attr> Header<A> {
         self.append(Theme::light())
     }
     pub fn dark_theme(self) -> elem::Append<Self, Theme<'static>> {
-        self.append(Theme::light())
+        self.append(Theme::dark())
+    }
+
+    ///
+    /// Style the plot with a custom css theme, for example one built using [`theme::ThemeBuilder`].
+    ///
+    pub fn custom_theme(self, theme: Theme) -> elem::Append<Self, Theme> {
+        self.append(theme)
     }
 }
 
@@ -586,11 +1047,26 @@ impl<A: Attr> Elem for Header<A> {
 ///
 /// Default theme
 ///
+/// Use [`theme::ThemeBuilder`] to generate a custom one.
+///
 #[derive(Copy, Clone)]
 pub struct Theme<'a> {
     styles: &'a str,
 }
 
+impl<'a> Theme<'a> {
+    ///
+    /// Create a theme from a css string, for example one rendered by [`theme::ThemeBuilder::css`].
+    ///
+    pub const fn new(styles: &'a str) -> Theme<'a> {
+        Theme { styles }
+    }
+
+    pub const fn get_str(&self) -> &'a str {
+        self.styles
+    }
+}
+
 impl Theme<'static> {
     pub const fn light() -> Theme<'static> {
         /// Default light theme
@@ -669,10 +1145,6 @@ impl Theme<'static> {
             styles: STYLE_CONFIG_DARK_DEFAULT,
         }
     }
-
-    pub const fn get_str(&self) -> &'static str {
-        self.styles
-    }
 }
 
 impl<'a> Locked for Theme<'a> {}
@@ -0,0 +1,121 @@
+//!
+//! Rasterize plots to PNG without any external tools. Requires the `png` feature.
+//!
+//! The svg is rendered with [resvg](https://github.com/RazrFalcon/resvg), which applies the css of
+//! the embedded [`Theme`] like a browser would. Text is drawn using the fonts installed on the system.
+//!
+use super::*;
+use resvg::usvg::fontdb;
+use resvg::{tiny_skia, usvg};
+use std::path::Path;
+
+///
+/// An error that occurred while rendering a PNG.
+///
+#[derive(Debug)]
+pub enum PngError {
+    /// The svg could not be written.
+    Fmt(fmt::Error),
+    /// The svg could not be parsed.
+    Svg(usvg::Error),
+    /// The scaled image has a width or height of zero, or is too big.
+    Size,
+    /// The PNG could not be encoded.
+    Encode(String),
+    /// The PNG file could not be written.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for PngError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PngError::Fmt(_) => write!(f, "failed to write the svg"),
+            PngError::Svg(e) => write!(f, "failed to parse the svg: {}", e),
+            PngError::Size => write!(f, "invalid image size"),
+            PngError::Encode(e) => write!(f, "failed to encode the png: {}", e),
+            PngError::Io(e) => write!(f, "failed to write the png: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PngError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PngError::Fmt(e) => Some(e),
+            PngError::Svg(e) => Some(e),
+            PngError::Io(e) => Some(e),
+            PngError::Size | PngError::Encode(_) => None,
+        }
+    }
+}
+
+impl From<fmt::Error> for PngError {
+    fn from(e: fmt::Error) -> Self {
+        PngError::Fmt(e)
+    }
+}
+
+impl<R: Elem + Locked> Stage4<R> {
+    ///
+    /// Render the plot to a PNG file. `scale` multiplies the width and height of the svg,
+    /// so `2.0` gives an image with twice the resolution.
+    ///
+    pub fn render_png<P: AsRef<Path>>(self, path: P, scale: f32) -> Result<(), PngError> {
+        let data = self.render_png_bytes(scale)?;
+        std::fs::write(path, data).map_err(PngError::Io)
+    }
+
+    ///
+    /// Render the plot to the bytes of a PNG image. See [`Stage4::render_png`].
+    ///
+    pub fn render_png_bytes(self, scale: f32) -> Result<Vec<u8>, PngError> {
+        let svg = self.render_string()?;
+        rasterize(&svg, scale)
+    }
+}
+
+fn rasterize(svg: &str, scale: f32) -> Result<Vec<u8>, PngError> {
+    let mut opt = usvg::Options::default();
+    let db = opt.fontdb_mut();
+    db.load_system_fonts();
+
+    //Sans-serif defaults to Arial, which often isn't installed on linux.
+    let query = fontdb::Query {
+        families: &[fontdb::Family::SansSerif],
+        ..Default::default()
+    };
+    if db.query(&query).is_none() {
+        let family = ["Liberation Sans", "DejaVu Sans", "Noto Sans"]
+            .into_iter()
+            .find(|&name| {
+                db.faces()
+                    .any(|face| face.families.iter().any(|(family, _)| family == name))
+            })
+            .map(String::from)
+            .or_else(|| Some(db.faces().next()?.families.first()?.0.clone()));
+
+        if let Some(family) = family {
+            db.set_sans_serif_family(family);
+        }
+    }
+
+    let tree = usvg::Tree::from_str(svg, &opt).map_err(PngError::Svg)?;
+
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(scale)
+        .ok_or(PngError::Size)?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).ok_or(PngError::Size)?;
+
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .encode_png()
+        .map_err(|e| PngError::Encode(e.to_string()))
+}
@@ -1,12 +1,158 @@
 use super::*;
 
+const TITLE_LINE_HEIGHT: f64 = 28.8;
+const SUBTITLE_FONT_SIZE: f64 = 18.0;
+const SUBTITLE_LINE_HEIGHT: f64 = 21.6;
+const CAPTION_FONT_SIZE: f64 = 14.0;
+const CAPTION_LINE_HEIGHT: f64 = 16.8;
+
+/// Estimated width of one character of tick text.
+pub(super) const TICK_CHAR_WIDTH: f64 = 9.6;
+const TICK_LINE_HEIGHT: f64 = 19.2;
+
+#[derive(Copy, Clone)]
+enum XTickLayout {
+    Horizontal,
+    Rotate(f64),
+    Stagger,
+    Thin(usize),
+}
+
+///
+/// Decide how to lay out the x tick labels, given the screen position of every tick,
+/// and how much vertical room there is between the x axis and the x name.
+///
+fn xtick_layout(mode: TickLabels, xpos: &[f64], labels: &[String], room: f64) -> XTickLayout {
+    let widest = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) as f64;
+    let needed = (widest + 1.0) * TICK_CHAR_WIDTH;
+
+    let spacing = xpos
+        .windows(2)
+        .map(|w| (w[1] - w[0]).abs())
+        .fold(f64::INFINITY, f64::min);
+
+    let thin = || XTickLayout::Thin((needed / spacing).ceil().max(1.0) as usize);
+
+    match mode {
+        TickLabels::Horizontal => XTickLayout::Horizontal,
+        TickLabels::Rotate(0.0) => XTickLayout::Horizontal,
+        TickLabels::Rotate(angle) => XTickLayout::Rotate(angle),
+        TickLabels::Stagger => XTickLayout::Stagger,
+        TickLabels::Thin => thin(),
+        TickLabels::Auto => {
+            use std::f64::consts::FRAC_1_SQRT_2;
+
+            if needed <= spacing {
+                XTickLayout::Horizontal
+            } else if TICK_LINE_HEIGHT / FRAC_1_SQRT_2 <= spacing
+                && widest * TICK_CHAR_WIDTH * FRAC_1_SQRT_2 <= room
+            {
+                XTickLayout::Rotate(-45.0)
+            } else if needed <= spacing * 2.0 {
+                XTickLayout::Stagger
+            } else {
+                thin()
+            }
+        }
+    }
+}
+
+///
+/// The text of a [`BaseFmt`], written out ahead of time so that
+/// space can be made for text that spans multiple lines.
+///
+pub(super) struct Texts {
+    title: String,
+    subtitle: String,
+    caption: String,
+    xname: String,
+    yname: String,
+}
+
+impl Texts {
+    pub(super) fn new(base: &mut dyn BaseFmt) -> Result<Self, fmt::Error> {
+        let mut texts = Texts {
+            title: String::new(),
+            subtitle: String::new(),
+            caption: String::new(),
+            xname: String::new(),
+            yname: String::new(),
+        };
+        base.write_title(&mut texts.title)?;
+        base.write_subtitle(&mut texts.subtitle)?;
+        base.write_caption(&mut texts.caption)?;
+        base.write_xname(&mut texts.xname)?;
+        base.write_yname(&mut texts.yname)?;
+        Ok(texts)
+    }
+
+    ///
+    /// The space in addition to the margins that the text needs as `[top, bottom]`.
+    ///
+    pub(super) fn extra_space(&self, canvas: &RenderOptionsResult) -> [f64; 2] {
+        let top = (num_lines(&self.title).max(1) - 1) as f64 * TITLE_LINE_HEIGHT
+            + num_lines(&self.subtitle) as f64 * SUBTITLE_LINE_HEIGHT;
+
+        let xname = if canvas.hide_xlabels {
+            0
+        } else {
+            num_lines(&self.xname).max(1) - 1
+        };
+
+        let bottom = xname as f64 * TITLE_LINE_HEIGHT + caption_height(&self.caption);
+
+        [top, bottom]
+    }
+}
+
+fn num_lines(text: &str) -> usize {
+    if text.is_empty() {
+        0
+    } else {
+        text.lines().count()
+    }
+}
+
+fn caption_height(caption: &str) -> f64 {
+    match num_lines(caption) {
+        0 => 0.0,
+        n => n as f64 * CAPTION_LINE_HEIGHT + CAPTION_LINE_HEIGHT / 2.0,
+    }
+}
+
+///
+/// Write text that may contain newlines. Each line after the first is put in a `tspan`
+/// that is moved down by the line height.
+///
+fn multiline<'a>(text: &'a str, x: f64, line_height: f64) -> impl Elem + Locked + 'a {
+    hbuild::from_closure(move |w| {
+        if num_lines(text) <= 1 {
+            use std::fmt::Write;
+            return w.writer().write_str(text);
+        }
+
+        for (i, line) in text.lines().enumerate() {
+            let dy = if i == 0 { 0.0 } else { line_height };
+            w.render(
+                hbuild::elem("tspan")
+                    .with(attrs!(("x", x), ("dy", dy)))
+                    .append(hbuild::from_closure(move |w| {
+                        use std::fmt::Write;
+                        w.writer().write_str(line)
+                    })),
+            )?;
+        }
+        Ok(())
+    })
+}
+
 pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
     writer: &mut elem::ElemWrite,
     xticksg: impl TickDist<Num = X>,
     yticksg: impl TickDist<Num = Y>,
     boundx: &ticks::DataBound<X>,
     boundy: &ticks::DataBound<Y>,
-    plot_fmt: &mut dyn BaseFmt,
+    texts: &Texts,
     canvas: &RenderOptionsResult,
 ) -> std::fmt::Result {
     use crate::ticks::tick_fmt::TickFmt;
@@ -19,6 +165,9 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
         height,
         padding,
         paddingy,
+        margin_top,
+        margin_bottom,
+        margin_left,
         xaspect_offset,
         yaspect_offset,
         ..
@@ -42,19 +191,64 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
         ("y", padding / 4.0)
     ));
 
-    let title = hbuild::from_closure(|w| plot_fmt.write_title(&mut w.writer()));
+    writer.render(text.append(multiline(&texts.title, width / 2.0, TITLE_LINE_HEIGHT)))?;
+
+    if !texts.subtitle.is_empty() {
+        let y = padding / 4.0
+            + num_lines(&texts.title).max(1) as f64 * TITLE_LINE_HEIGHT
+            + (SUBTITLE_LINE_HEIGHT - TITLE_LINE_HEIGHT) / 2.0;
+
+        let text = hbuild::elem("text").with(attrs!(
+            ("class", "poloto_labels poloto_text poloto_subtitle"),
+            ("font-size", SUBTITLE_FONT_SIZE),
+            ("dominant-baseline", "start"),
+            ("text-anchor", "middle"),
+            ("x", width / 2.0),
+            ("y", y)
+        ));
+
+        writer.render(text.append(multiline(
+            &texts.subtitle,
+            width / 2.0,
+            SUBTITLE_LINE_HEIGHT,
+        )))?;
+    }
+
+    let caption_height = caption_height(&texts.caption);
+
+    if caption_height > 0.0 {
+        let y = height - caption_height + CAPTION_LINE_HEIGHT / 2.0;
+
+        let text = hbuild::elem("text").with(attrs!(
+            ("class", "poloto_labels poloto_text poloto_caption"),
+            ("font-size", CAPTION_FONT_SIZE),
+            ("dominant-baseline", "hanging"),
+            ("text-anchor", "start"),
+            ("x", textx_padding),
+            ("y", y)
+        ));
+
+        writer.render(text.append(multiline(
+            &texts.caption,
+            textx_padding,
+            CAPTION_LINE_HEIGHT,
+        )))?;
+    }
 
-    writer.render(text.append(title))?;
+    let xname_y = height
+        - padding / 8.
+        - caption_height
+        - (num_lines(&texts.xname).max(1) - 1) as f64 * TITLE_LINE_HEIGHT;
 
     let text = hbuild::elem("text").with(attrs!(
         ("class", "poloto_labels poloto_text poloto_xname"),
         ("x", width / 2.0),
-        ("y", height - padding / 8.)
+        ("y", xname_y)
     ));
 
-    let xname = hbuild::from_closure(|w| plot_fmt.write_xname(&mut w.writer()));
-
-    writer.render(text.append(xname))?;
+    if !canvas.hide_xlabels {
+        writer.render(text.append(multiline(&texts.xname, width / 2.0, TITLE_LINE_HEIGHT)))?;
+    }
 
     let text = hbuild::elem("text").with(attrs!(
         ("class", "poloto_labels poloto_text poloto_yname"),
@@ -66,9 +260,13 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
         ("y", height / 2.0)
     ));
 
-    let yname = hbuild::from_closure(|w| plot_fmt.write_yname(&mut w.writer()));
+    if !canvas.hide_ylabels {
+        writer.render(text.append(multiline(&texts.yname, padding / 4.0, TITLE_LINE_HEIGHT)))?;
+    }
 
-    writer.render(text.append(yname))?;
+    if canvas.polar {
+        return render_base_polar(writer, xticksg, yticksg, boundx, boundy, canvas);
+    }
 
     let xdash_size = xticksg.res.dash_size;
     let ydash_size = yticksg.res.dash_size;
@@ -130,25 +328,60 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
             ("class", "poloto_tick_labels poloto_text"),
             ("dominant-baseline", "middle"),
             ("text-anchor", "start"),
-            ("x", padding),
-            ("y", paddingy * 0.7)
+            ("x", margin_left),
+            ("y", margin_top - texty_padding)
         ));
 
         let ywher = hbuild::from_closure(|w| yticksg.fmt.write_where(&mut w.writer()));
 
-        writer.render(text.append(ywher))?;
+        if !canvas.hide_ylabels {
+            writer.render(text.append(ywher))?;
+        }
+
+        for val in yticksg.fmt.minor_ticks().iter() {
+            if *val < boundy[0] || *val > boundy[1] {
+                continue;
+            }
+
+            let yy = height
+                - (val.scale([miny, maxy], scaley) - miny.scale([miny, maxy], scaley))
+                - margin_bottom;
+
+            if canvas.minor_ticks[1] {
+                writer.render(hbuild::single("line").with(attrs!(
+                    ("class", "poloto_axis_lines poloto_minor_tick"),
+                    ("stroke", "black"),
+                    ("x1", xaspect_offset + margin_left),
+                    ("x2", xaspect_offset + margin_left - padding * 0.02),
+                    ("y1", yaspect_offset + yy),
+                    ("y2", yaspect_offset + yy)
+                )))?;
+            }
+
+            if canvas.minor_tick_lines[1] {
+                writer.render(hbuild::single("line").with(attrs!(
+                    ("class", "poloto_tick_line poloto_minor_tick_line"),
+                    ("stroke", "black"),
+                    ("stroke-opacity", 0.5),
+                    ("x1", xaspect_offset + margin_left),
+                    ("x2", margin_left + xaspect_offset + distancex_min_to_max),
+                    ("y1", yaspect_offset + yy),
+                    ("y2", yaspect_offset + yy)
+                )))?;
+            }
+        }
 
         //Draw interval y text
         for val in std::iter::once(first_ticky).chain(yticks) {
             let yy = height
                 - (val.scale([miny, maxy], scaley) - miny.scale([miny, maxy], scaley))
-                - paddingy;
+                - margin_bottom;
 
             writer.render(hbuild::single("line").with(attrs!(
                 ("class", "poloto_axis_lines"),
                 ("stroke", "black"),
-                ("x1", xaspect_offset + padding),
-                ("x2", xaspect_offset + padding * 0.96),
+                ("x1", xaspect_offset + margin_left),
+                ("x2", xaspect_offset + margin_left - padding * 0.04),
                 ("y1", yaspect_offset + yy),
                 ("y2", yaspect_offset + yy)
             )))?;
@@ -157,24 +390,29 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
                 writer.render(hbuild::single("line").with(attrs!(
                     ("class", "poloto_tick_line"),
                     ("stroke", "black"),
-                    ("x1", xaspect_offset + padding),
-                    ("x2", padding + xaspect_offset + distancex_min_to_max),
+                    ("x1", xaspect_offset + margin_left),
+                    ("x2", margin_left + xaspect_offset + distancex_min_to_max),
                     ("y1", yaspect_offset + yy),
                     ("y2", yaspect_offset + yy)
                 )))?;
             }
 
             let text = hbuild::elem("text").with(attrs!(
-                ("class", "poloto_tick_labels poloto_text"),
+                (
+                    "class",
+                    "poloto_tick_labels poloto_ytick_labels poloto_text"
+                ),
                 ("dominant-baseline", "middle"),
                 ("text-anchor", "end"),
-                ("x", xaspect_offset + padding - textx_padding),
+                ("x", xaspect_offset + margin_left - textx_padding),
                 ("y", yaspect_offset + yy)
             ));
 
             let ytick = hbuild::from_closure(|w| yticksg.fmt.write_tick(&mut w.writer(), &val));
 
-            writer.render(text.append(ytick))?;
+            if !canvas.hide_ylabels {
+                writer.render(text.append(ytick))?;
+            }
         }
     }
 
@@ -184,51 +422,146 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
             ("dominant-baseline", "middle"),
             ("text-anchor", "start"),
             ("x", width * 0.55),
-            ("y", paddingy * 0.7)
+            ("y", margin_top - texty_padding)
         ));
 
         let xwher = hbuild::from_closure(|w| xticksg.fmt.write_where(&mut w.writer()));
 
-        writer.render(text.append(xwher))?;
+        if !canvas.hide_xlabels {
+            writer.render(text.append(xwher))?;
+        }
 
-        //Draw interva`l x text
-        for val in std::iter::once(first_tickx).chain(xticks) {
-            let xx = (val.scale([minx, maxx], scalex) - minx.scale([minx, maxx], scalex)) + padding;
+        let xvals: Vec<_> = std::iter::once(first_tickx).chain(xticks).collect();
+
+        let xpos: Vec<_> = xvals
+            .iter()
+            .map(|val| {
+                (val.scale([minx, maxx], scalex) - minx.scale([minx, maxx], scalex))
+                    + margin_left
+                    + xaspect_offset
+            })
+            .collect();
+
+        let mut xlabels = Vec::new();
+        if !canvas.hide_xlabels {
+            for val in xvals.iter() {
+                let mut s = String::new();
+                xticksg.fmt.write_tick(&mut s, val)?;
+                xlabels.push(s);
+            }
+        }
+
+        let bottom = yaspect_offset + height - margin_bottom;
+
+        let room = xname_y - (bottom + paddingy * 0.1) - TICK_LINE_HEIGHT / 2.0;
+        let layout = xtick_layout(canvas.xtick_labels, &xpos, &xlabels, room);
+
+        for val in xticksg.fmt.minor_ticks().iter() {
+            if *val < boundx[0] || *val > boundx[1] {
+                continue;
+            }
 
+            let xx = (val.scale([minx, maxx], scalex) - minx.scale([minx, maxx], scalex))
+                + margin_left
+                + xaspect_offset;
+
+            if canvas.minor_ticks[0] {
+                writer.render(hbuild::single("line").with(attrs!(
+                    ("class", "poloto_axis_lines poloto_minor_tick"),
+                    ("stroke", "black"),
+                    ("x1", xx),
+                    ("x2", xx),
+                    ("y1", bottom),
+                    ("y2", bottom + paddingy * 0.025)
+                )))?;
+            }
+
+            if canvas.minor_tick_lines[0] {
+                writer.render(hbuild::single("line").with(attrs!(
+                    ("class", "poloto_tick_line poloto_minor_tick_line"),
+                    ("stroke", "black"),
+                    ("stroke-opacity", 0.5),
+                    ("x1", xx),
+                    ("x2", xx),
+                    ("y1", bottom),
+                    ("y2", bottom - distancey_min_to_max)
+                )))?;
+            }
+        }
+
+        //Draw interva`l x text
+        for (i, &xx) in xpos.iter().enumerate() {
             writer.render(hbuild::single("line").with(attrs!(
                 ("class", "poloto_axis_lines"),
                 ("stroke", "black"),
-                ("x1", xaspect_offset + xx),
-                ("x2", xaspect_offset + xx),
-                ("y1", yaspect_offset + height - paddingy),
-                ("y2", yaspect_offset + height - paddingy * 0.95)
+                ("x1", xx),
+                ("x2", xx),
+                ("y1", bottom),
+                ("y2", bottom + paddingy * 0.05)
             )))?;
 
             if canvas.xtick_lines {
                 writer.render(hbuild::single("line").with(attrs!(
                     ("class", "poloto_tick_line"),
                     ("stroke", "black"),
-                    ("x1", xaspect_offset + xx),
-                    ("x2", xaspect_offset + xx),
-                    ("y1", yaspect_offset + height - paddingy),
-                    (
-                        "y2",
-                        yaspect_offset + height - paddingy - distancey_min_to_max,
-                    )
+                    ("x1", xx),
+                    ("x2", xx),
+                    ("y1", bottom),
+                    ("y2", bottom - distancey_min_to_max)
                 )))?;
             }
 
-            let text = hbuild::elem("text").with(attrs!(
-                ("class", "poloto_tick_labels poloto_text"),
-                ("dominant-baseline", "start"),
-                ("text-anchor", "middle"),
-                ("x", xaspect_offset + xx),
-                ("y", yaspect_offset + height - paddingy + texty_padding)
-            ));
-
-            let xtick = hbuild::from_closure(|w| xticksg.fmt.write_tick(&mut w.writer(), &val));
-
-            writer.render(text.append(xtick))?;
+            let label = match xlabels.get(i) {
+                Some(label) => label.as_str(),
+                None => continue,
+            };
+
+            let xtick = hbuild::from_closure(|w| {
+                use std::fmt::Write;
+                w.writer().write_str(label)
+            });
+
+            match layout {
+                XTickLayout::Horizontal | XTickLayout::Stagger | XTickLayout::Thin(_) => {
+                    let y = match layout {
+                        XTickLayout::Stagger if i % 2 == 1 => {
+                            bottom + texty_padding + TICK_LINE_HEIGHT
+                        }
+                        XTickLayout::Thin(n) if i % n != 0 => continue,
+                        _ => bottom + texty_padding,
+                    };
+
+                    let text = hbuild::elem("text").with(attrs!(
+                        (
+                            "class",
+                            "poloto_tick_labels poloto_xtick_labels poloto_text"
+                        ),
+                        ("dominant-baseline", "start"),
+                        ("text-anchor", "middle"),
+                        ("x", xx),
+                        ("y", y)
+                    ));
+
+                    writer.render(text.append(xtick))?;
+                }
+                XTickLayout::Rotate(angle) => {
+                    let y = bottom + paddingy * 0.1;
+
+                    let text = hbuild::elem("text").with(attrs!(
+                        (
+                            "class",
+                            "poloto_tick_labels poloto_xtick_labels poloto_text"
+                        ),
+                        ("dominant-baseline", "middle"),
+                        ("text-anchor", if angle < 0.0 { "end" } else { "start" }),
+                        ("transform", format_move!("rotate({},{},{})", angle, xx, y)),
+                        ("x", xx),
+                        ("y", y)
+                    ));
+
+                    writer.render(text.append(xtick))?;
+                }
+            }
         }
     }
 
@@ -253,10 +586,13 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
         ("class", "poloto_axis_lines"),
         xclosure,
         hbuild::path([
-            M(padding + xaspect_offset, height - paddingy + yaspect_offset,),
+            M(
+                margin_left + xaspect_offset,
+                height - margin_bottom + yaspect_offset,
+            ),
             L(
-                padding + xaspect_offset + distancex_min_to_max,
-                height - paddingy + yaspect_offset,
+                margin_left + xaspect_offset + distancex_min_to_max,
+                height - margin_bottom + yaspect_offset,
             )
         ])
     )))?;
@@ -280,13 +616,154 @@ pub(super) fn render_base<X: PlotNum, Y: PlotNum>(
         ("class", "poloto_axis_lines"),
         yclosure,
         hbuild::path([
-            M(xaspect_offset + padding, yaspect_offset + height - paddingy,),
+            M(
+                xaspect_offset + margin_left,
+                yaspect_offset + height - margin_bottom,
+            ),
             L(
-                xaspect_offset + padding,
-                yaspect_offset + height - paddingy - distancey_min_to_max,
+                xaspect_offset + margin_left,
+                yaspect_offset + height - margin_bottom - distancey_min_to_max,
             )
         ])
     )))?;
 
     Ok(())
 }
+
+fn render_base_polar<X: PlotNum, Y: PlotNum>(
+    writer: &mut elem::ElemWrite,
+    mut xticksg: TickDistribution<impl IntoIterator<Item = X>, impl tick_fmt::TickFmt<X>>,
+    mut yticksg: TickDistribution<impl IntoIterator<Item = Y>, impl tick_fmt::TickFmt<Y>>,
+    boundx: [X; 2],
+    boundy: [Y; 2],
+    canvas: &RenderOptionsResult,
+) -> std::fmt::Result {
+    use std::f64::consts::TAU;
+
+    let RenderOptionsResult {
+        width,
+        padding,
+        paddingy,
+        margin_top,
+        margin_left,
+        precision,
+        ..
+    } = *canvas;
+
+    let ffmt = FloatFmt::new(precision);
+
+    let ([cx, cy], radius) = canvas.polar_circle();
+
+    let [minx, maxx] = boundx;
+    let [miny, maxy] = boundy;
+
+    let text = hbuild::elem("text").with(attrs!(
+        ("class", "poloto_tick_labels poloto_text"),
+        ("dominant-baseline", "middle"),
+        ("text-anchor", "start"),
+        ("x", margin_left),
+        ("y", margin_top - paddingy * 0.3)
+    ));
+
+    let ywher = hbuild::from_closure(|w| yticksg.fmt.write_where(&mut w.writer()));
+
+    writer.render(text.append(ywher))?;
+
+    //Draw the circular grid lines with the y tick text along the zero angle.
+    for val in yticksg
+        .iter
+        .into_iter()
+        .skip_while(|&y| y < miny)
+        .take_while(|&y| y <= maxy)
+    {
+        let r = val.scale([miny, maxy], radius) - miny.scale([miny, maxy], radius);
+
+        if r > 0.0 {
+            writer.render(hbuild::single("circle").with(attrs!(
+                ("class", "poloto_tick_line poloto_polar_ring"),
+                ("stroke", "black"),
+                ("fill", "none"),
+                ("cx", ffmt.disp(cx)),
+                ("cy", ffmt.disp(cy)),
+                ("r", ffmt.disp(r))
+            )))?;
+        }
+
+        let text = hbuild::elem("text").with(attrs!(
+            ("class", "poloto_tick_labels poloto_text"),
+            ("dominant-baseline", "start"),
+            ("text-anchor", "middle"),
+            ("x", ffmt.disp(cx + r)),
+            ("y", ffmt.disp(cy + paddingy * 0.2))
+        ));
+
+        let ytick = hbuild::from_closure(|w| yticksg.fmt.write_tick(&mut w.writer(), &val));
+
+        writer.render(text.append(ytick))?;
+    }
+
+    let text = hbuild::elem("text").with(attrs!(
+        ("class", "poloto_tick_labels poloto_text"),
+        ("dominant-baseline", "middle"),
+        ("text-anchor", "start"),
+        ("x", width * 0.55),
+        ("y", margin_top - paddingy * 0.3)
+    ));
+
+    let xwher = hbuild::from_closure(|w| xticksg.fmt.write_where(&mut w.writer()));
+
+    writer.render(text.append(xwher))?;
+
+    //Draw a spoke and the angle text for every x tick.
+    //The end of the range is skipped since it lands on the start of the range.
+    for val in xticksg
+        .iter
+        .into_iter()
+        .skip_while(|&x| x < minx)
+        .take_while(|&x| x < maxx)
+    {
+        let angle = val.scale([minx, maxx], TAU) - minx.scale([minx, maxx], TAU);
+        let (dx, dy) = (angle.cos(), -angle.sin());
+
+        writer.render(hbuild::single("line").with(attrs!(
+            ("class", "poloto_tick_line poloto_polar_spoke"),
+            ("stroke", "black"),
+            ("x1", ffmt.disp(cx)),
+            ("y1", ffmt.disp(cy)),
+            ("x2", ffmt.disp(cx + dx * radius)),
+            ("y2", ffmt.disp(cy + dy * radius))
+        )))?;
+
+        let anchor = if dx.abs() < 0.1 {
+            "middle"
+        } else if dx > 0.0 {
+            "start"
+        } else {
+            "end"
+        };
+
+        let offset = radius + padding * 0.1;
+        let text = hbuild::elem("text").with(attrs!(
+            ("class", "poloto_tick_labels poloto_text"),
+            ("dominant-baseline", "middle"),
+            ("text-anchor", anchor),
+            ("x", ffmt.disp(cx + dx * offset)),
+            ("y", ffmt.disp(cy + dy * offset))
+        ));
+
+        let xtick = hbuild::from_closure(|w| xticksg.fmt.write_tick(&mut w.writer(), &val));
+
+        writer.render(text.append(xtick))?;
+    }
+
+    writer.render(hbuild::single("circle").with(attrs!(
+        ("class", "poloto_axis_lines"),
+        ("stroke", "black"),
+        ("fill", "none"),
+        ("cx", ffmt.disp(cx)),
+        ("cy", ffmt.disp(cy)),
+        ("r", ffmt.disp(radius))
+    )))?;
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use super::*;
 
+use crate::build::style::{Fill, MarkerShape, SeriesAttrs, SeriesStyle};
+use crate::build::waterfall::WaterfallPart;
 use crate::build::*;
 
 pub(super) fn render_plot<P: build::PlotIterator>(
@@ -8,12 +10,17 @@ pub(super) fn render_plot<P: build::PlotIterator>(
     boundy: &ticks::DataBound<P::Y>,
     canvas: &RenderOptionsResult,
     plots_all: &mut P,
+    prefix: &str,
 ) -> std::fmt::Result {
     let RenderOptionsResult {
         width,
         height,
         padding,
         paddingy,
+        margin_top,
+        margin_bottom,
+        margin_left,
+        legend_width,
         xaspect_offset,
         yaspect_offset,
         spacing,
@@ -42,97 +49,849 @@ pub(super) fn render_plot<P: build::PlotIterator>(
 
     let mut f = crate::build::RenderablePlotIter::new(plots_all);
 
-    for i in 0.. {
-        let mut ppp = if let Some(ppp) = f.next_plot() {
-            ppp
+    let to_screen = {
+        let basex_ii = xaspect_offset + margin_left - minx.scale([minx, maxx], scalex);
+        let basey_ii = yaspect_offset + height - margin_bottom + miny.scale([miny, maxy], scaley);
+        let rangex_ii = [minx, maxx];
+        let rangey_ii = [miny, maxy];
+        let maxx_ii = scalex;
+        let maxy_ii = scaley;
+        let polar_ii = if canvas.polar {
+            Some(canvas.polar_circle())
         } else {
-            break;
+            None
         };
 
-        let legendy1 = paddingy - yaspect_offset - padding / 8.0 + (i as f64) * spacing;
+        move |(x, y): (P::X, P::Y)| {
+            if let Some(([cx, cy], radius)) = polar_ii {
+                let angle = x.scale(rangex_ii, std::f64::consts::TAU)
+                    - minx.scale(rangex_ii, std::f64::consts::TAU);
+                let r = y.scale(rangey_ii, radius) - miny.scale(rangey_ii, radius);
+                [cx + r * angle.cos(), cy - r * angle.sin()]
+            } else {
+                [
+                    basex_ii + x.scale(rangex_ii, maxx_ii),
+                    basey_ii - y.scale(rangey_ii, maxy_ii),
+                ]
+            }
+        }
+    };
 
-        let typ = ppp.typ();
+    let legend_slot = |i: usize| {
+        let i = i as f64;
+        match canvas.legend {
+            Legend::Top(columns) | Legend::Bottom(columns) => {
+                let columns = columns.max(1) as f64;
+                let (row, col) = ((i / columns).floor(), i % columns);
+                let x = xaspect_offset + margin_left + col * (scalex / columns);
+                let y = if let Legend::Top(_) = canvas.legend {
+                    margin_top - spacing * canvas.legend_rows as f64 - paddingy * 0.3
+                } else {
+                    height - margin_bottom + spacing + paddingy * 0.1
+                };
+                [x, y + padding / 8.0 + row * spacing]
+            }
+            //Relative to the top left corner of the legend box.
+            Legend::Inside(_) => [10.0, 10.0 + padding / 8.0 + i * spacing],
+            Legend::Right | Legend::Hidden => [
+                width - legend_width,
+                margin_top - yaspect_offset + i * spacing,
+            ],
+        }
+    };
 
-        let text = hbuild::elem("text").with(attrs!(
-            ("class", "poloto_text poloto_legend_text"),
-            ("x", width - padding / 1.2),
-            ("y", paddingy - yaspect_offset + (i as f64) * spacing)
-        ));
+    let mut inside = Vec::new();
+    let mut widest = 0;
 
-        let name_exists = text.render_closure(writer, |w| {
-            let mut wc = util::WriteCounter::new(w.writer());
-            ppp.name(&mut wc).unwrap()?;
-            //p.write_name(&mut wc)?;
-            Ok(wc.get_counter() != 0)
-        })?;
+    let mut defs = Defs {
+        prefix: prefix.to_string(),
+        written: Vec::new(),
+    };
 
-        let aa = minx.scale([minx, maxx], scalex);
-        let bb = miny.scale([miny, maxy], scaley);
+    let mut i = 0;
+    while let Some(mut ppp) = f.next_plot() {
+        let typ = ppp.typ();
 
+        //Reference lines and annotations are labeled inside the plot area,
+        //so they don't take up a legend entry.
         match typ {
-            PlotMetaType::Text => {
-                assert_eq!(ppp.plots().count(), 0);
+            PlotMetaType::Plot(PlotType::Reference) => {
+                let mut name = String::new();
+                ppp.name(&mut name).unwrap()?;
+
+                let details = ppp.details();
+                let points: Vec<_> = ppp.plots().map(to_screen).collect();
+
+                if let (Some(kind), false) = (details.reference, canvas.polar) {
+                    render_reference(writer, &points, kind, &name, canvas)?;
+                }
+                continue;
+            }
+            PlotMetaType::Plot(PlotType::Annotation) => {
+                let mut name = String::new();
+                ppp.name(&mut name).unwrap()?;
 
-                // don't need to render any legend or plots
+                let details = ppp.details();
+                let points: Vec<_> = ppp.plots().map(to_screen).collect();
+
+                if let (Some(&point), Some(style)) = (points.first(), details.annotation) {
+                    render_annotation(writer, point, style, &name, canvas)?;
+                }
+                continue;
             }
-            PlotMetaType::Plot(p_type) => {
-                let colori = color_iter.next().unwrap();
-
-                let mut it = {
-                    let basex_ii = xaspect_offset + padding - aa;
-                    let basey_ii = yaspect_offset + height - paddingy + bb;
-                    let rangex_ii = [minx, maxx];
-                    let rangey_ii = [miny, maxy];
-                    let maxx_ii = scalex;
-                    let maxy_ii = scaley;
-
-                    ppp.plots().map(move |(x, y)| {
-                        [
-                            basex_ii + x.scale(rangex_ii, maxx_ii),
-                            basey_ii - y.scale(rangey_ii, maxy_ii),
-                        ]
-                    })
-                };
+            _ => {}
+        }
 
-                //
-                // Using `cargo bloat` determined that these lines reduces alot of code bloat.
-                // in debug builds.
-                //
-                let it: &mut dyn Iterator<Item = [f64; 2]> = &mut it;
+        let mut name = String::new();
+        ppp.name(&mut name).unwrap()?;
 
-                let precision = canvas.precision;
-                render(
-                    writer,
-                    it,
-                    PlotRenderInfo {
-                        canvas,
-                        p_type,
-                        name_exists,
-                        colori,
-                        legendy1,
-                        precision,
-                        bar_width: canvas.bar_width,
-                    },
+        let attrs = ppp.attrs();
+        let style = ppp.style();
+        let details = ppp.details();
+
+        let name_exists = !name.is_empty() && canvas.legend != Legend::Hidden;
+
+        //Where the legend text goes. The legend icon is drawn above it.
+        let [legendx, legendy] = legend_slot(i);
+        let legendx1 = legendx + padding / 30.0;
+        let legendy1 = legendy - padding / 8.0;
+
+        //Buffer the legend entry while the plot is drawn,
+        //so that an inside legend can be drawn on top of all the plots.
+        let entry = elem::BufferedElem::new(hbuild::from_closure(|legend| {
+            if name_exists {
+                legend.render(
+                    hbuild::elem("text")
+                        .with(attrs!(
+                            ("class", "poloto_text poloto_legend_text"),
+                            ("x", legendx),
+                            ("y", legendy)
+                        ))
+                        .append(hbuild::from_closure(|w| {
+                            use std::fmt::Write;
+                            w.writer().write_str(&name)
+                        })),
                 )?;
             }
+
+            match typ {
+                PlotMetaType::Text => {
+                    assert_eq!(ppp.plots().count(), 0);
+
+                    // don't need to render any legend or plots
+                }
+                PlotMetaType::Plot(p_type) => {
+                    let colori = color_iter.next().unwrap();
+
+                    let mut it = {
+                        ppp.plots()
+                            .map(to_screen)
+                            //Plot types that consume points in pairs may ask for one past the end.
+                            //Don't let that pull a point from the next plot.
+                            .fuse()
+                    };
+
+                    //
+                    // Using `cargo bloat` determined that these lines reduces alot of code bloat.
+                    // in debug builds.
+                    //
+                    let it: &mut dyn Iterator<Item = [f64; 2]> = &mut it;
+
+                    //Polar plots only support the plot types that are made up of lines.
+                    let (p_type, style) = match p_type {
+                        PlotType::Histo | PlotType::Bars if canvas.polar => {
+                            (PlotType::Line, SeriesStyle::default())
+                        }
+                        _ => (p_type, style),
+                    };
+
+                    let precision = canvas.precision;
+                    render(
+                        writer,
+                        legend,
+                        it,
+                        PlotRenderInfo {
+                            canvas,
+                            p_type,
+                            name_exists,
+                            colori,
+                            legendx1,
+                            legendy1,
+                            precision,
+                            bar_width: canvas.bar_width,
+                            defs: &mut defs,
+                            attrs: &attrs,
+                            style,
+                            details,
+                        },
+                    )?;
+                }
+            }
+            Ok(())
+        }))?;
+
+        if name_exists {
+            widest = widest.max(name.chars().count());
+        }
+
+        if let Legend::Inside(_) = canvas.legend {
+            if name_exists {
+                inside.push(entry);
+            }
+        } else {
+            writer.render(&entry)?;
+        }
+
+        //Plots without a name don't take up a slot in the legend.
+        if !name.is_empty() {
+            i += 1;
+        }
+    }
+
+    if let (Legend::Inside(corner), false) = (canvas.legend, inside.is_empty()) {
+        //Estimate the size of the box from the longest name.
+        let box_width = (widest as f64 * 11.0).max(padding / 3.0) + padding / 30.0 + 20.0;
+        let box_height = (inside.len() as f64) * spacing;
+
+        let left = xaspect_offset + margin_left;
+        let bottom = yaspect_offset + height - margin_bottom;
+        let gap = 10.0;
+
+        let x = match corner {
+            Corner::TopLeft | Corner::BottomLeft => left + gap,
+            Corner::TopRight | Corner::BottomRight => left + scalex - box_width - gap,
+        };
+        let y = match corner {
+            Corner::TopLeft | Corner::TopRight => bottom - scaley + gap,
+            Corner::BottomLeft | Corner::BottomRight => bottom - box_height - gap,
+        };
+
+        let g = hbuild::elem("g").with(attrs!(
+            ("class", "poloto_legend"),
+            ("transform", format_move!("translate({} {})", x, y))
+        ));
+
+        let h = hbuild::from_closure(|w| {
+            w.render(hbuild::single("rect").with(attrs!(
+                ("class", "poloto_background poloto_legend_box"),
+                ("stroke", "gray"),
+                ("fill-opacity", 0.9),
+                ("width", box_width),
+                ("height", box_height),
+                ("rx", padding / 30.0)
+            )))?;
+            for entry in inside.iter() {
+                w.render(entry)?;
+            }
+            Ok(())
+        });
+
+        writer.render(g.append(h))?;
+    }
+
+    Ok(())
+}
+
+fn render_annotation(
+    writer: &mut elem::ElemWrite,
+    [x, y]: [f64; 2],
+    style: annotation::AnnotationStyle,
+    name: &str,
+    canvas: &RenderOptionsResult,
+) -> fmt::Result {
+    if !(x.is_finite() && y.is_finite()) {
+        return Ok(());
+    }
+
+    let RenderOptionsResult {
+        padding, precision, ..
+    } = *canvas;
+
+    let ffmt = FloatFmt::new(precision);
+
+    let radius = padding / 25.0;
+    let [dx, dy] = style.offset;
+    let [tx, ty] = [x + dx, y - dy];
+
+    if style.highlight {
+        writer.render(hbuild::single("circle").with(attrs!(
+            ("class", "poloto_annotation poloto_annotation_highlight"),
+            ("stroke", "black"),
+            ("fill", "none"),
+            ("cx", ffmt.disp(x)),
+            ("cy", ffmt.disp(y)),
+            ("r", ffmt.disp(radius))
+        )))?;
+    }
+
+    let dist = dx.hypot(dy);
+    if style.arrow && dist > radius {
+        //Stop short of the point so that the arrowhead doesn't cover it.
+        let [ux, uy] = [-dx / dist, dy / dist];
+        let [hx, hy] = [x - ux * radius, y - uy * radius];
+        let head = padding / 20.0;
+
+        writer.render(hbuild::single("line").with(attrs!(
+            ("class", "poloto_annotation poloto_annotation_arrow"),
+            ("stroke", "black"),
+            ("x1", ffmt.disp(tx)),
+            ("y1", ffmt.disp(ty)),
+            ("x2", ffmt.disp(hx)),
+            ("y2", ffmt.disp(hy))
+        )))?;
+
+        use attr::PathCommand::*;
+        writer.render(hbuild::single("path").with(attrs!(
+            ("class", "poloto_annotation poloto_annotation_arrow"),
+            ("fill", "black"),
+            hbuild::path_from_closure(|w| {
+                let mut w = w.start();
+                let [bx, by] = [hx - ux * head, hy - uy * head];
+                w.put(M(ffmt.disp(hx), ffmt.disp(hy)))?;
+                w.put(L(
+                    ffmt.disp(bx - uy * head / 2.0),
+                    ffmt.disp(by + ux * head / 2.0),
+                ))?;
+                w.put(L(
+                    ffmt.disp(bx + uy * head / 2.0),
+                    ffmt.disp(by - ux * head / 2.0),
+                ))?;
+                w.put(Z())
+            })
+        )))?;
+    }
+
+    if !name.is_empty() {
+        //Place the text on the side of the offset away from the point.
+        let anchor = if dx > 0.0 {
+            "start"
+        } else if dx < 0.0 {
+            "end"
+        } else {
+            "middle"
+        };
+
+        let baseline = if dy > 0.0 {
+            "auto"
+        } else if dy < 0.0 {
+            "hanging"
+        } else {
+            "middle"
+        };
+
+        writer.render(
+            hbuild::elem("text")
+                .with(attrs!(
+                    (
+                        "class",
+                        "poloto_text poloto_annotation poloto_annotation_text"
+                    ),
+                    ("text-anchor", anchor),
+                    ("dominant-baseline", baseline),
+                    ("x", ffmt.disp(tx)),
+                    ("y", ffmt.disp(ty))
+                ))
+                .append(name),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn render_reference(
+    writer: &mut elem::ElemWrite,
+    points: &[[f64; 2]],
+    kind: reference::RefKind,
+    name: &str,
+    canvas: &RenderOptionsResult,
+) -> fmt::Result {
+    use reference::RefKind;
+
+    let RenderOptionsResult {
+        height,
+        padding,
+        margin_bottom,
+        margin_left,
+        xaspect_offset,
+        yaspect_offset,
+        precision,
+        ..
+    } = *canvas;
+
+    let ffmt = FloatFmt::new(precision);
+
+    let left = margin_left + xaspect_offset;
+    let right = left + canvas.boundx.max;
+    let bottom = height - margin_bottom + yaspect_offset;
+    let top = bottom - canvas.boundy.max;
+
+    let clampx = |x: f64| x.max(left).min(right);
+    let clampy = |y: f64| y.max(top).min(bottom);
+
+    //Position of the label, and its anchor.
+    let label = match kind {
+        RefKind::HLine | RefKind::HBand => {
+            let [_, y] = points[0];
+            let (y, y2) = match points.get(1) {
+                Some(&[_, y2]) => (y.min(y2), y.max(y2)),
+                None => (y, y),
+            };
+            if y2 < top || y > bottom {
+                return Ok(());
+            }
+
+            if let RefKind::HLine = kind {
+                writer.render(hbuild::single("line").with(attrs!(
+                    ("class", "poloto_refline poloto_hline"),
+                    ("stroke", "black"),
+                    ("stroke-dasharray", "8 4"),
+                    ("x1", ffmt.disp(left)),
+                    ("x2", ffmt.disp(right)),
+                    ("y1", ffmt.disp(y)),
+                    ("y2", ffmt.disp(y))
+                )))?;
+            } else {
+                let (y1, y2) = (clampy(y), clampy(y2));
+                writer.render(hbuild::single("rect").with(attrs!(
+                    ("class", "poloto_refband poloto_hband"),
+                    ("fill", "black"),
+                    ("fill-opacity", 0.1),
+                    ("x", ffmt.disp(left)),
+                    ("y", ffmt.disp(y1)),
+                    ("width", ffmt.disp(right - left)),
+                    ("height", ffmt.disp(y2 - y1))
+                )))?;
+            }
+
+            (
+                right - padding / 30.0,
+                clampy(y) - padding / 30.0,
+                "end",
+                "auto",
+            )
+        }
+        RefKind::VLine | RefKind::VBand => {
+            let [x, _] = points[0];
+            let (x, x2) = match points.get(1) {
+                Some(&[x2, _]) => (x.min(x2), x.max(x2)),
+                None => (x, x),
+            };
+            if x2 < left || x > right {
+                return Ok(());
+            }
+
+            if let RefKind::VLine = kind {
+                writer.render(hbuild::single("line").with(attrs!(
+                    ("class", "poloto_refline poloto_vline"),
+                    ("stroke", "black"),
+                    ("stroke-dasharray", "8 4"),
+                    ("x1", ffmt.disp(x)),
+                    ("x2", ffmt.disp(x)),
+                    ("y1", ffmt.disp(top)),
+                    ("y2", ffmt.disp(bottom))
+                )))?;
+            } else {
+                let (x1, x2) = (clampx(x), clampx(x2));
+                writer.render(hbuild::single("rect").with(attrs!(
+                    ("class", "poloto_refband poloto_vband"),
+                    ("fill", "black"),
+                    ("fill-opacity", 0.1),
+                    ("x", ffmt.disp(x1)),
+                    ("y", ffmt.disp(top)),
+                    ("width", ffmt.disp(x2 - x1)),
+                    ("height", ffmt.disp(bottom - top))
+                )))?;
+            }
+
+            (
+                clampx(x) + padding / 30.0,
+                top + padding / 30.0,
+                "start",
+                "hanging",
+            )
         }
+    };
+
+    if !name.is_empty() {
+        let (x, y, anchor, baseline) = label;
+        writer.render(
+            hbuild::elem("text")
+                .with(attrs!(
+                    ("class", "poloto_text poloto_ref_label"),
+                    ("text-anchor", anchor),
+                    ("dominant-baseline", baseline),
+                    ("x", ffmt.disp(x)),
+                    ("y", ffmt.disp(y))
+                ))
+                .append(name),
+        )?;
     }
 
     Ok(())
 }
 
+//The default size of a marker, matching the size of the points of a scatter plot.
+const MARKER_SIZE: f64 = 7.0;
+
+fn inline_style(css: &str) -> impl attr::Attr + '_ {
+    hbuild::attr_from_closure(move |w| {
+        if css.is_empty() {
+            Ok(())
+        } else {
+            w.render(("style", css))
+        }
+    })
+}
+
+///
+/// The id, inline style and attributes the user gave a plot, or its legend icon.
+/// The user's style is written after the css of the plot's own style, so it takes precedence.
+///
+fn user_attrs<'a>(attrs: &'a SeriesAttrs, legend: bool, css: &'a str) -> impl attr::Attr + 'a {
+    hbuild::attr_from_closure(move |w| {
+        if let Some(id) = attrs.id(legend) {
+            w.render(("id", id))?;
+        }
+        match attrs.style() {
+            Some(style) => w.render(("style", format_move!("{}{}", css, style)))?,
+            None => w.render(inline_style(css))?,
+        }
+        for (name, value) in attrs.attrs() {
+            w.render((name.as_str(), value.as_str()))?;
+        }
+        Ok(())
+    })
+}
+
+///
+/// Draw a shape of the given size at each point.
+///
+/// The shapes get the user's css classes. If they are the plot itself rather than markers on a line,
+/// the second part of `user` says whether they are the legend icon, and they get the rest of the user's attributes.
+///
+fn render_markers(
+    writer: &mut elem::ElemWrite,
+    it: impl Iterator<Item = [f64; 2]>,
+    marker: MarkerShape,
+    size: f64,
+    colori: usize,
+    user: (&SeriesAttrs, Option<bool>),
+    ffmt: &FloatFmt,
+) -> fmt::Result {
+    let it = it.filter(|&[x, y]| x.is_finite() && y.is_finite());
+
+    let (attrs, id) = user;
+    let user = hbuild::attr_from_closure(|w| match id {
+        Some(legend) => w.render(user_attrs(attrs, legend, "")),
+        None => Ok(()),
+    });
+
+    //A circle is a line of zero length with round caps, like the points of a scatter plot.
+    if let MarkerShape::Circle = marker {
+        return writer.render(hbuild::single("path").with(attrs!(
+            (
+                "class",
+                format_move!("poloto_marker poloto{}stroke{}", colori, attrs.class())
+            ),
+            ("stroke", "black"),
+            ("stroke-width", size),
+            user,
+            hbuild::path_from_closure(|w| {
+                let mut w = w.start();
+                use hypermelon::attr::PathCommand::*;
+                for [x, y] in it {
+                    w.put(M(ffmt.disp(x), ffmt.disp(y)))?;
+                    w.put(H_(ffmt.disp(0.0)))?;
+                }
+                Ok(())
+            })
+        )));
+    }
+
+    let r = size / 2.0;
+    writer.render(hbuild::single("path").with(attrs!(
+        (
+            "class",
+            format_move!("poloto_marker poloto{}fill{}", colori, attrs.class())
+        ),
+        user,
+        hbuild::path_from_closure(|w| {
+            let mut w = w.start();
+            use hypermelon::attr::PathCommand::*;
+            for [x, y] in it {
+                match marker {
+                    MarkerShape::Square => {
+                        w.put(M(ffmt.disp(x - r), ffmt.disp(y - r)))?;
+                        w.put(H_(ffmt.disp(size)))?;
+                        w.put(V_(ffmt.disp(size)))?;
+                        w.put(H_(ffmt.disp(-size)))?;
+                    }
+                    MarkerShape::Triangle => {
+                        //Make the triangle a bit bigger so it looks about as big as the other shapes.
+                        let r = r * 1.3;
+                        w.put(M(ffmt.disp(x), ffmt.disp(y - r)))?;
+                        w.put(L(ffmt.disp(x + r * 0.866), ffmt.disp(y + r * 0.5)))?;
+                        w.put(L(ffmt.disp(x - r * 0.866), ffmt.disp(y + r * 0.5)))?;
+                    }
+                    MarkerShape::Circle => unreachable!(),
+                    MarkerShape::Diamond => {
+                        let r = r * 1.3;
+                        w.put(M(ffmt.disp(x), ffmt.disp(y - r)))?;
+                        w.put(L(ffmt.disp(x + r), ffmt.disp(y)))?;
+                        w.put(L(ffmt.disp(x), ffmt.disp(y + r)))?;
+                        w.put(L(ffmt.disp(x - r), ffmt.disp(y)))?;
+                    }
+                }
+                w.put(Z())?;
+            }
+            Ok(())
+        })
+    )))
+}
+
+///
+/// The ids of the gradients, patterns and markers of a render.
+///
+struct Defs {
+    prefix: String,
+    written: Vec<String>,
+}
+
+impl Defs {
+    ///
+    /// The id of an element with the prefix of this render, so that it doesn't clash
+    /// with the elements of other plots in the same document.
+    ///
+    fn id(&self, name: impl Display) -> String {
+        format!("{}_{}", self.prefix, name)
+    }
+
+    ///
+    /// Returns true the first time it is called with a name, so that each def is only written once.
+    ///
+    fn first(&mut self, name: impl Display) -> bool {
+        let id = self.id(name);
+        if self.written.contains(&id) {
+            false
+        } else {
+            self.written.push(id);
+            true
+        }
+    }
+}
+
+///
+/// Write the clip path of the plot area if it hasn't been written yet, and return its id.
+///
+fn plot_area_clip(
+    writer: &mut elem::ElemWrite,
+    canvas: &RenderOptionsResult,
+    defs: &mut Defs,
+) -> Result<String, fmt::Error> {
+    let id = defs.id("clip");
+    if defs.first("clip") {
+        let RenderOptionsResult {
+            height,
+            margin_bottom,
+            margin_left,
+            xaspect_offset,
+            yaspect_offset,
+            ..
+        } = *canvas;
+
+        let scalex = canvas.boundx.max;
+        let scaley = canvas.boundy.max;
+        let bottom = height - margin_bottom + yaspect_offset;
+
+        writer.render(
+            hbuild::elem("defs").append(hbuild::elem("clipPath").with(("id", id.as_str())).append(
+                hbuild::single("rect").with(attrs!(
+                    ("x", margin_left + xaspect_offset),
+                    ("y", bottom - scaley),
+                    ("width", scalex),
+                    ("height", scaley)
+                )),
+            )),
+        )?;
+    }
+    Ok(id)
+}
+
+///
+/// The classes that give a filled shape its gradient or pattern.
+///
+struct FillAttrs {
+    class: String,
+}
+
+impl FillAttrs {
+    ///
+    /// Write the defs of the fill, and the css rules that point its classes at them,
+    /// if they haven't been written yet.
+    ///
+    fn new(
+        writer: &mut elem::ElemWrite,
+        fill: Option<Fill>,
+        colori: usize,
+        defs: &mut Defs,
+    ) -> Result<FillAttrs, fmt::Error> {
+        let fill = match fill {
+            Some(Fill::Solid) | None => {
+                return Ok(FillAttrs {
+                    class: String::new(),
+                })
+            }
+            Some(fill) => fill,
+        };
+
+        let name = match fill {
+            Fill::Fade => "fade",
+            Fill::Hatch => "hatch",
+            Fill::CrossHatch => "crosshatch",
+            Fill::Dots => "dots",
+            Fill::Solid => unreachable!(),
+        };
+
+        //The fade mask doesn't depend on the color, so it is shared by all plots.
+        let (key, class) = if let Fill::Fade = fill {
+            (name.to_string(), format!(" poloto_{}", name))
+        } else {
+            (
+                format!("{}{}", name, colori),
+                format!(" poloto_{} poloto{}{}", name, colori, name),
+            )
+        };
+
+        if defs.first(&key) {
+            render_fill_defs(writer, fill, colori, defs)?;
+        }
+
+        Ok(FillAttrs { class })
+    }
+}
+
+fn render_fill_defs(
+    writer: &mut elem::ElemWrite,
+    fill: Fill,
+    colori: usize,
+    defs: &Defs,
+) -> fmt::Result {
+    let pattern = |name: &'static str| {
+        hbuild::elem("pattern").with(attrs!(
+            ("id", defs.id(format_args!("{}{}", name, colori))),
+            ("patternUnits", "userSpaceOnUse"),
+            ("width", 8),
+            ("height", 8),
+            ("patternTransform", "rotate(45)")
+        ))
+    };
+
+    let stroke = |[x2, y2]: [i32; 2]| {
+        hbuild::single("line").with(attrs!(
+            ("class", format_move!("poloto{}stroke", colori)),
+            ("stroke", "black"),
+            ("stroke-width", 2),
+            ("x1", 0),
+            ("y1", 0),
+            ("x2", x2),
+            ("y2", y2)
+        ))
+    };
+
+    let fade = defs.id("fade");
+    let fade_gradient = defs.id("fade_gradient");
+
+    let elems = hbuild::from_closure(|w| match fill {
+        Fill::Fade => {
+            w.render(
+                hbuild::elem("linearGradient")
+                    .with(attrs!(
+                        ("id", fade_gradient.as_str()),
+                        ("x1", 0),
+                        ("y1", 0),
+                        ("x2", 0),
+                        ("y2", 1)
+                    ))
+                    .append(
+                        hbuild::single("stop").with(attrs!(("offset", 0), ("stop-color", "white"))),
+                    )
+                    .append(hbuild::single("stop").with(attrs!(
+                        ("offset", 1),
+                        ("stop-color", "white"),
+                        ("stop-opacity", 0)
+                    ))),
+            )?;
+            w.render(
+                hbuild::elem("mask")
+                    .with(attrs!(
+                        ("id", fade.as_str()),
+                        ("maskContentUnits", "objectBoundingBox")
+                    ))
+                    .append(hbuild::single("rect").with(attrs!(
+                        ("width", 1),
+                        ("height", 1),
+                        ("fill", format_move!("url(#{})", fade_gradient))
+                    ))),
+            )
+        }
+        Fill::Hatch => w.render(pattern("hatch").append(stroke([0, 8]))),
+        Fill::CrossHatch => w.render(
+            pattern("crosshatch")
+                .append(stroke([0, 8]))
+                .append(stroke([8, 0])),
+        ),
+        Fill::Dots => w.render(pattern("dots").append(hbuild::single("circle").with(attrs!(
+            ("class", format_move!("poloto{}fill", colori)),
+            ("cx", 4),
+            ("cy", 4),
+            ("r", 1.5)
+        )))),
+        Fill::Solid => Ok(()),
+    });
+
+    //Point the classes of the filled shapes at the defs.
+    let rule = match fill {
+        Fill::Fade => format!(".poloto_fade{{mask:url(#{})}}", fade),
+        Fill::Hatch => format!(
+            ".poloto{0}hatch{{fill:url(#{1})}}",
+            colori,
+            defs.id(format_args!("hatch{}", colori))
+        ),
+        Fill::CrossHatch => format!(
+            ".poloto{0}crosshatch{{fill:url(#{1})}}",
+            colori,
+            defs.id(format_args!("crosshatch{}", colori))
+        ),
+        Fill::Dots => format!(
+            ".poloto{0}dots{{fill:url(#{1})}}",
+            colori,
+            defs.id(format_args!("dots{}", colori))
+        ),
+        Fill::Solid => String::new(),
+    };
+
+    writer.render(hbuild::elem("defs").append(elems))?;
+    writer.render(hbuild::elem("style").append(rule.as_str()))
+}
+
 struct PlotRenderInfo<'a> {
     canvas: &'a RenderOptionsResult,
     p_type: PlotType,
     name_exists: bool,
     colori: usize,
+    legendx1: f64,
     legendy1: f64,
     precision: usize,
     bar_width: f64,
+    defs: &'a mut Defs,
+    attrs: &'a SeriesAttrs,
+    style: SeriesStyle,
+    details: PlotDetails,
 }
 
 fn render(
     writer: &mut elem::ElemWrite,
+    legend: &mut elem::ElemWrite,
     it: impl Iterator<Item = [f64; 2]>,
     info: PlotRenderInfo,
 ) -> fmt::Result {
@@ -141,72 +900,166 @@ fn render(
         p_type,
         name_exists,
         colori,
+        legendx1,
         legendy1,
         precision,
         bar_width,
+        defs,
+        attrs,
+        style,
+        details,
     } = info;
 
     let RenderOptionsResult {
         height,
         padding,
-        paddingy,
-        legendx1,
+        margin_bottom,
+        margin_left,
         ..
     } = *canvas;
 
     let ffmt = FloatFmt::new(precision);
 
+    use attr::PathCommand::*;
+
     match p_type {
         PlotType::Line => {
+            let css = style.inline_css();
+
             if name_exists {
-                writer.render(hbuild::single("line").with(attrs!(
+                legend.render(hbuild::single("line").with(attrs!(
                     (
                         "class",
                         format_move!(
-                            "poloto_line poloto_legend_icon poloto{}stroke poloto{}legend",
+                            "poloto_line poloto_legend_icon poloto{}stroke poloto{}dash poloto{}legend{}",
+                            colori,
+                            colori,
                             colori,
-                            colori
+                            attrs.class()
                         )
                     ),
                     ("stroke", "black"),
                     ("x1", legendx1),
                     ("x2", legendx1 + padding / 3.0),
                     ("y1", legendy1),
-                    ("y2", legendy1)
+                    ("y2", legendy1),
+                    user_attrs(attrs, true, &css)
                 )))?;
+
+                if let Some(marker) = style.marker {
+                    let point = [legendx1 + padding / 6.0, legendy1];
+                    render_markers(
+                        legend,
+                        std::iter::once(point),
+                        marker,
+                        MARKER_SIZE,
+                        colori,
+                        (attrs, None),
+                        &ffmt,
+                    )?;
+                }
             }
 
+            //The points are needed twice if there are markers.
+            let points: Vec<_> = it.collect();
+
             writer.render(hbuild::single("path").with(attrs!(
-                ("class", format_move!("poloto_line poloto{}stroke", colori)),
+                (
+                    "class",
+                    format_move!(
+                        "poloto_line poloto{}stroke poloto{}dash{}",
+                        colori,
+                        colori,
+                        attrs.class()
+                    )
+                ),
                 ("fill", "none"),
                 ("stroke", "black"),
-                Line::new(it, ffmt)
+                user_attrs(attrs, false, &css),
+                Line::new(points.iter().copied(), ffmt)
             )))?;
+
+            if let Some(marker) = style.marker {
+                let ffmt = FloatFmt::new(precision);
+                render_markers(
+                    writer,
+                    points.into_iter(),
+                    marker,
+                    MARKER_SIZE,
+                    colori,
+                    (attrs, None),
+                    &ffmt,
+                )?;
+            }
         }
         PlotType::Scatter => {
+            let marker = match style.marker {
+                Some(marker) => Some(marker),
+                None if canvas.auto_markers => {
+                    Some(MarkerShape::ALL[colori % MarkerShape::ALL.len()])
+                }
+                None => None,
+            };
+
+            if let Some(marker) = marker {
+                let size = style.width.unwrap_or(MARKER_SIZE);
+
+                if name_exists {
+                    let point = [legendx1 + padding / 30.0, legendy1];
+                    render_markers(
+                        legend,
+                        std::iter::once(point),
+                        marker,
+                        size,
+                        colori,
+                        (attrs, Some(true)),
+                        &ffmt,
+                    )?;
+                }
+
+                render_markers(
+                    writer,
+                    it,
+                    marker,
+                    size,
+                    colori,
+                    (attrs, Some(false)),
+                    &ffmt,
+                )?;
+                return Ok(());
+            }
+
+            let css = match style.width {
+                Some(width) => format!("stroke-width:{};", width),
+                None => String::new(),
+            };
+
             if name_exists {
-                writer.render(hbuild::single("line").with(attrs!(
+                legend.render(hbuild::single("line").with(attrs!(
                     (
                         "class",
                         format_move!(
-                            "poloto_scatter poloto_legend_icon poloto{}stroke poloto{}legend",
+                            "poloto_scatter poloto_legend_icon poloto{}stroke poloto{}legend{}",
                             colori,
-                            colori
+                            colori,
+                            attrs.class()
                         ),
                     ),
                     ("stroke", "black"),
                     ("x1", legendx1 + padding / 30.0),
                     ("x2", legendx1 + padding / 30.0),
                     ("y1", legendy1),
-                    ("y2", legendy1)
+                    ("y2", legendy1),
+                    user_attrs(attrs, true, &css)
                 )))?;
             }
 
             writer.render(hbuild::single("path").with(attrs!(
                 (
                     "class",
-                    format_move!("poloto_scatter poloto{}stroke", colori),
+                    format_move!("poloto_scatter poloto{}stroke{}", colori, attrs.class()),
                 ),
+                user_attrs(attrs, false, &css),
                 hbuild::path_from_closure(|w| {
                     let mut w = w.start();
                     use hypermelon::attr::PathCommand::*;
@@ -219,14 +1072,18 @@ fn render(
             )))?;
         }
         PlotType::Histo => {
+            let fill = &FillAttrs::new(writer, style.fill, colori, defs)?;
+
             if name_exists {
-                writer.render(hbuild::single("rect").with(attrs!(
+                legend.render(hbuild::single("rect").with(attrs!(
                     (
                         "class",
                         format_move!(
-                            "poloto_histo poloto_legend_icon poloto{}fill poloto{}legend",
+                            "poloto_histo poloto_legend_icon poloto{}fill poloto{}legend{}{}",
+                            colori,
                             colori,
-                            colori
+                            fill.class,
+                            attrs.class()
                         ),
                     ),
                     ("x", legendx1),
@@ -234,12 +1091,23 @@ fn render(
                     ("width", padding / 3.0),
                     ("height", padding / 20.0),
                     ("rx", padding / 30.0),
-                    ("ry", padding / 30.0)
+                    ("ry", padding / 30.0),
+                    user_attrs(attrs, true, "")
                 )))?;
             }
 
-            let g = hbuild::elem("g")
-                .with(("class", format_move!("poloto_histo poloto{}fill", colori)));
+            let g = hbuild::elem("g").with(attrs!(
+                (
+                    "class",
+                    format_move!(
+                        "poloto_histo poloto{}fill{}{}",
+                        colori,
+                        fill.class,
+                        attrs.class()
+                    )
+                ),
+                user_attrs(attrs, false, "")
+            ));
 
             let h = hbuild::from_closure(|w| {
                 let mut last = None;
@@ -249,7 +1117,7 @@ fn render(
                             ("x", ffmt.disp(lx)),
                             ("y", ffmt.disp(ly)),
                             ("width", (padding * 0.02).max((x - lx) - (padding * 0.02))),
-                            ("height", height - paddingy - ly)
+                            ("height", height - margin_bottom - ly)
                         )))?;
                     }
                     last = Some((x, y))
@@ -260,14 +1128,18 @@ fn render(
             writer.render(g.append(h))?;
         }
         PlotType::LineFill => {
+            let fill = &FillAttrs::new(writer, style.fill, colori, defs)?;
+
             if name_exists {
-                writer.render(hbuild::single("rect").with(attrs!(
+                legend.render(hbuild::single("rect").with(attrs!(
                     (
                         "class",
                         format_move!(
-                            "poloto_linefill poloto_legend_icon poloto{}fill poloto{}legend",
+                            "poloto_linefill poloto_legend_icon poloto{}fill poloto{}legend{}{}",
                             colori,
-                            colori
+                            colori,
+                            fill.class,
+                            attrs.class()
                         ),
                     ),
                     ("x", legendx1),
@@ -275,27 +1147,59 @@ fn render(
                     ("width", padding / 3.0),
                     ("height", padding / 20.0),
                     ("rx", padding / 30.0),
-                    ("ry", padding / 30.0)
+                    ("ry", padding / 30.0),
+                    user_attrs(attrs, true, "")
                 )))?;
             }
 
-            writer.render(hbuild::single("path").with(attrs!(
-                (
-                    "class",
-                    format_move!("poloto_linefill poloto{}fill", colori),
-                ),
-                LineFill::new(it, ffmt, height - paddingy, true)
-            )))?;
+            if canvas.polar {
+                //Fill towards the center instead of towards the x axis.
+                let (center, _) = canvas.polar_circle();
+                let it = std::iter::once(center)
+                    .chain(it)
+                    .chain(std::iter::once(center));
+                writer.render(hbuild::single("path").with(attrs!(
+                    (
+                        "class",
+                        format_move!(
+                            "poloto_linefill poloto{}fill{}{}",
+                            colori,
+                            fill.class,
+                            attrs.class()
+                        ),
+                    ),
+                    user_attrs(attrs, false, ""),
+                    LineFill::new(it, ffmt, height - margin_bottom, false)
+                )))?;
+            } else {
+                writer.render(hbuild::single("path").with(attrs!(
+                    (
+                        "class",
+                        format_move!(
+                            "poloto_linefill poloto{}fill{}{}",
+                            colori,
+                            fill.class,
+                            attrs.class()
+                        ),
+                    ),
+                    user_attrs(attrs, false, ""),
+                    LineFill::new(it, ffmt, height - margin_bottom, true)
+                )))?;
+            }
         }
         PlotType::LineFillRaw => {
+            let fill = &FillAttrs::new(writer, style.fill, colori, defs)?;
+
             if name_exists {
-                writer.render(hbuild::single("rect").with(attrs!(
+                legend.render(hbuild::single("rect").with(attrs!(
                     (
                         "class",
                         format_move!(
-                            "poloto_linefillraw poloto_legend_icon poloto{}fill poloto{}legend",
+                            "poloto_linefillraw poloto_legend_icon poloto{}fill poloto{}legend{}{}",
                             colori,
-                            colori
+                            colori,
+                            fill.class,
+                            attrs.class()
                         ),
                     ),
                     ("x", legendx1),
@@ -303,27 +1207,38 @@ fn render(
                     ("width", padding / 3.0),
                     ("height", padding / 20.0),
                     ("rx", padding / 30.0),
-                    ("ry", padding / 30.0)
+                    ("ry", padding / 30.0),
+                    user_attrs(attrs, true, "")
                 )))?;
             }
 
             writer.render(hbuild::single("path").with(attrs!(
                 (
                     "class",
-                    format_move!("poloto_linefill poloto{}fill", colori),
+                    format_move!(
+                        "poloto_linefill poloto{}fill{}{}",
+                        colori,
+                        fill.class,
+                        attrs.class()
+                    ),
                 ),
-                LineFill::new(it, ffmt, height - paddingy, false)
+                user_attrs(attrs, false, ""),
+                LineFill::new(it, ffmt, height - margin_bottom, false)
             )))?;
         }
         PlotType::Bars => {
+            let fill = &FillAttrs::new(writer, style.fill, colori, defs)?;
+
             if name_exists {
-                writer.render(hbuild::single("rect").with(attrs!(
+                legend.render(hbuild::single("rect").with(attrs!(
                     (
                         "class",
                         format_move!(
-                            "poloto_histo poloto_legend_icon poloto{}fill poloto{}legend",
+                            "poloto_histo poloto_legend_icon poloto{}fill poloto{}legend{}{}",
+                            colori,
                             colori,
-                            colori
+                            fill.class,
+                            attrs.class()
                         ),
                     ),
                     ("x", legendx1),
@@ -331,25 +1246,295 @@ fn render(
                     ("width", padding / 3.0),
                     ("height", padding / 20.0),
                     ("rx", padding / 30.0),
-                    ("ry", padding / 30.0)
+                    ("ry", padding / 30.0),
+                    user_attrs(attrs, true, "")
                 )))?;
             }
 
-            let g = hbuild::elem("g")
-                .with(("class", format_move!("poloto_histo poloto{}fill", colori)));
+            let g = hbuild::elem("g").with(attrs!(
+                (
+                    "class",
+                    format_move!(
+                        "poloto_histo poloto{}fill{}{}",
+                        colori,
+                        fill.class,
+                        attrs.class()
+                    )
+                ),
+                user_attrs(attrs, false, "")
+            ));
 
             let h = hbuild::from_closure(|w| {
                 for [x, y] in it.filter(|&[x, y]| x.is_finite() && y.is_finite()) {
                     w.render(hbuild::single("rect").with(attrs!(
-                        ("x", ffmt.disp(padding)),
+                        ("x", ffmt.disp(margin_left)),
                         ("y", ffmt.disp(y - bar_width / 2.0)),
-                        ("width", x - padding),
+                        ("width", x - margin_left),
                         ("height", bar_width)
                     )))?;
                 }
                 Ok(())
             });
 
+            writer.render(g.append(h))?;
+        }
+        PlotType::Waterfall if matches!(details.waterfall, Some(WaterfallPart::Connector)) => {
+            writer.render(hbuild::single("path").with(attrs!(
+                (
+                    "class",
+                    format_move!("poloto_waterfall_connector{}", attrs.class())
+                ),
+                ("stroke", "black"),
+                ("fill", "none"),
+                user_attrs(attrs, false, ""),
+                hbuild::path_from_closure(|w| {
+                    let mut w = w.start();
+                    let mut it = it;
+                    while let (Some([x1, y1]), Some([x2, y2])) = (it.next(), it.next()) {
+                        w.put(M(ffmt.disp(x1), ffmt.disp(y1 - bar_width / 2.0)))?;
+                        w.put(L(ffmt.disp(x2), ffmt.disp(y2 + bar_width / 2.0)))?;
+                    }
+                    Ok(())
+                })
+            )))?;
+        }
+        PlotType::Waterfall => {
+            let part = match details.waterfall {
+                Some(WaterfallPart::Increase) => "increase",
+                Some(WaterfallPart::Decrease) => "decrease",
+                _ => "total",
+            };
+
+            if name_exists {
+                legend.render(hbuild::single("rect").with(attrs!(
+                    (
+                        "class",
+                        format_move!(
+                            "poloto_waterfall poloto_waterfall_{} poloto_legend_icon poloto{}fill poloto{}legend{}",
+                            part,
+                            colori,
+                            colori,
+                            attrs.class()
+                        ),
+                    ),
+                    ("x", legendx1),
+                    ("y", legendy1 - padding / 30.0),
+                    ("width", padding / 3.0),
+                    ("height", padding / 20.0),
+                    ("rx", padding / 30.0),
+                    ("ry", padding / 30.0),
+                    user_attrs(attrs, true, "")
+                )))?;
+            }
+
+            let g = hbuild::elem("g").with(attrs!(
+                (
+                    "class",
+                    format_move!(
+                        "poloto_waterfall poloto_waterfall_{} poloto{}fill{}",
+                        part,
+                        colori,
+                        attrs.class()
+                    ),
+                ),
+                user_attrs(attrs, false, "")
+            ));
+
+            let h = hbuild::from_closure(|w| {
+                let mut it = it;
+                while let (Some([x1, y1]), Some([x2, _])) = (it.next(), it.next()) {
+                    if [x1, y1, x2].iter().all(|a| a.is_finite()) {
+                        w.render(hbuild::single("rect").with(attrs!(
+                            ("x", ffmt.disp(x1.min(x2))),
+                            ("y", ffmt.disp(y1 - bar_width / 2.0)),
+                            ("width", ffmt.disp((x2 - x1).abs())),
+                            ("height", bar_width)
+                        )))?;
+                    }
+                }
+                Ok(())
+            });
+
+            writer.render(g.append(h))?;
+        }
+        PlotType::Hexbin => {
+            let size = details.hexbin_size.unwrap_or(hexbin::DEFAULT_SIZE);
+            let bins = hexbin_count(it, size);
+
+            let (min, max) = bins
+                .values()
+                .fold((usize::MAX, 0), |(min, max), &c| (min.min(c), max.max(c)));
+
+            //Color the hexagons on a sequential scale from the emptiest to the fullest.
+            let color = |count: usize| {
+                let range = max.saturating_sub(min).max(1) as f64;
+                crate::render::theme::viridis(count.saturating_sub(min) as f64 / range)
+            };
+
+            if name_exists {
+                let steps = 5;
+                let step_width = padding / 3.0 / (steps as f64);
+                let g = hbuild::elem("g").with(attrs!(
+                    (
+                        "class",
+                        format_move!(
+                            "poloto_hexbin_colorbar poloto_legend_icon poloto{}legend{}",
+                            colori,
+                            attrs.class()
+                        ),
+                    ),
+                    user_attrs(attrs, true, "")
+                ));
+                let h = hbuild::from_closure(|w| {
+                    for i in 0..steps {
+                        w.render(hbuild::single("rect").with(attrs!(
+                            ("x", legendx1 + step_width * (i as f64)),
+                            ("y", legendy1 - padding / 30.0),
+                            ("width", step_width),
+                            ("height", padding / 20.0),
+                            (
+                                "fill",
+                                crate::render::theme::viridis(i as f64 / ((steps - 1) as f64))
+                            )
+                        )))?;
+                    }
+                    Ok(())
+                });
+                legend.render(g.append(h))?;
+
+                if max > 0 {
+                    for (count, x, anchor) in [
+                        (min, legendx1, "start"),
+                        (max, legendx1 + padding / 3.0, "end"),
+                    ] {
+                        legend.render(
+                            hbuild::elem("text")
+                                .with(attrs!(
+                                    ("class", "poloto_text poloto_hexbin_colorbar_label"),
+                                    ("font-size", padding / 12.0),
+                                    ("dominant-baseline", "hanging"),
+                                    ("text-anchor", anchor),
+                                    ("x", x),
+                                    ("y", legendy1 + padding / 30.0)
+                                ))
+                                .append(count),
+                        )?;
+                    }
+                }
+            }
+
+            //The hexagons at the edges would spill over the axes.
+            let clip = plot_area_clip(writer, canvas, defs)?;
+
+            let g = hbuild::elem("g").with(attrs!(
+                ("class", format_move!("poloto_hexbin{}", attrs.class())),
+                ("clip-path", format_move!("url(#{})", clip)),
+                user_attrs(attrs, false, "")
+            ));
+
+            let h = hbuild::from_closure(|w| {
+                for (&(q, r), &count) in bins.iter() {
+                    let [cx, cy] = hexbin_center(q, r, size);
+                    w.render(hbuild::single("path").with(attrs!(
+                        ("fill", color(count)),
+                        hbuild::path_from_closure(|w| {
+                            let mut w = w.start();
+                            for i in 0..6 {
+                                let angle = std::f64::consts::FRAC_PI_3 * (i as f64)
+                                    - std::f64::consts::FRAC_PI_6;
+                                let x = ffmt.disp(cx + size * angle.cos());
+                                let y = ffmt.disp(cy + size * angle.sin());
+                                if i == 0 {
+                                    w.put(M(x, y))?;
+                                } else {
+                                    w.put(L(x, y))?;
+                                }
+                            }
+                            w.put(Z())
+                        })
+                    )))?;
+                }
+                Ok(())
+            });
+
+            writer.render(g.append(h))?;
+        }
+        PlotType::Reference | PlotType::Annotation => {
+            //These are rendered by render_reference() and render_annotation().
+        }
+        PlotType::Quiver => {
+            let marker_id = defs.id(format_args!("arrow{}", colori));
+
+            //Series that share a color share a marker.
+            if defs.first(format_args!("arrow{}", colori)) {
+                let marker = hbuild::elem("marker").with(attrs!(
+                    ("id", marker_id.as_str()),
+                    ("viewBox", "0 0 10 10"),
+                    ("refX", 10),
+                    ("refY", 5),
+                    ("markerWidth", 5),
+                    ("markerHeight", 5),
+                    ("orient", "auto-start-reverse")
+                ));
+
+                let head = hbuild::single("path").with(attrs!(
+                    ("class", format_move!("poloto{}fill", colori)),
+                    hbuild::path([M(0, 0), L(10, 5), L(0, 10), Z()])
+                ));
+
+                writer.render(hbuild::elem("defs").append(marker.append(head)))?;
+            }
+            let marker_id = marker_id.as_str();
+
+            if name_exists {
+                legend.render(hbuild::single("line").with(attrs!(
+                    (
+                        "class",
+                        format_move!(
+                            "poloto_quiver poloto_legend_icon poloto{}stroke poloto{}legend{}",
+                            colori,
+                            colori,
+                            attrs.class()
+                        )
+                    ),
+                    ("stroke", "black"),
+                    ("marker-end", format_move!("url(#{})", marker_id)),
+                    ("x1", legendx1),
+                    ("x2", legendx1 + padding / 3.0),
+                    ("y1", legendy1),
+                    ("y2", legendy1),
+                    user_attrs(attrs, true, "")
+                )))?;
+            }
+
+            let g = hbuild::elem("g").with(attrs!(
+                (
+                    "class",
+                    format_move!("poloto_quiver poloto{}stroke{}", colori, attrs.class())
+                ),
+                ("stroke", "black"),
+                ("marker-end", format_move!("url(#{})", marker_id)),
+                user_attrs(attrs, false, "")
+            ));
+
+            let h = hbuild::from_closure(|w| {
+                let mut it = it;
+                while let (Some([x1, y1]), Some([x2, y2])) = (it.next(), it.next()) {
+                    let finite = [x1, y1, x2, y2].iter().all(|a| a.is_finite());
+
+                    //Zero length arrows have no direction to point the arrowhead in.
+                    if finite && (x1 != x2 || y1 != y2) {
+                        w.render(hbuild::single("line").with(attrs!(
+                            ("x1", ffmt.disp(x1)),
+                            ("y1", ffmt.disp(y1)),
+                            ("x2", ffmt.disp(x2)),
+                            ("y2", ffmt.disp(y2))
+                        )))?;
+                    }
+                }
+                Ok(())
+            });
+
             writer.render(g.append(h))?;
         }
     }
@@ -490,3 +1675,40 @@ impl FloatFmt {
         util::disp_const(move |f| write!(f, "{:.*}", precision, num))
     }
 }
+
+///
+/// Count the points that fall in each pointy topped hexagon of the given size.
+/// The hexagons are keyed by their axial coordinates.
+///
+pub(crate) fn hexbin_count(
+    it: impl Iterator<Item = [f64; 2]>,
+    size: f64,
+) -> std::collections::BTreeMap<(i64, i64), usize> {
+    let mut bins = std::collections::BTreeMap::new();
+    for [x, y] in it {
+        if !(x.is_finite() && y.is_finite()) {
+            continue;
+        }
+
+        let q = (3f64.sqrt() / 3.0 * x - y / 3.0) / size;
+        let r = (2.0 / 3.0 * y) / size;
+
+        //Round to the nearest hexagon using cube coordinates.
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+
+        *bins.entry((rq as i64, rr as i64)).or_insert(0) += 1;
+    }
+    bins
+}
+
+pub(crate) fn hexbin_center(q: i64, r: i64, size: f64) -> [f64; 2] {
+    let (q, r) = (q as f64, r as f64);
+    [size * 3f64.sqrt() * (q + r / 2.0), size * 1.5 * r]
+}
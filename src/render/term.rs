@@ -0,0 +1,444 @@
+//!
+//! Draw plots as text, for a quick look at data in a terminal.
+//!
+//! The plots are drawn using unicode braille or block characters, with an ANSI color per plot.
+//! The axis labels come from the same ticks as the svg.
+//!
+//! ```
+//! let data = poloto::data(poloto::build::plot("sin").line().buffered(
+//!     (0..50).map(|i| [i as f64, (i as f64 / 8.0).sin()]),
+//! ));
+//!
+//! let text = data
+//!     .build_and_label(("sine", "x", "y"))
+//!     .render_term_string(&poloto::render::term::TermOptions::new().with_color(false))
+//!     .unwrap();
+//!
+//! assert!(text.contains("sine"));
+//! ```
+//!
+use super::*;
+use crate::build::{PlotMetaType, PlotType};
+use crate::ticks::tick_fmt::TickFmt;
+
+///
+/// The characters used to draw the plots.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Charset {
+    /// Braille dots, 2 wide and 4 tall per character.
+    Braille,
+    /// Half blocks, 1 wide and 2 tall per character. Supported by more fonts than braille.
+    Block,
+}
+
+impl Charset {
+    //The number of dots in a character.
+    fn dots(&self) -> [usize; 2] {
+        match self {
+            Charset::Braille => [2, 4],
+            Charset::Block => [1, 2],
+        }
+    }
+
+    fn bit(&self, [x, y]: [usize; 2]) -> u8 {
+        match self {
+            Charset::Braille => {
+                const BITS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+                BITS[x][y]
+            }
+            Charset::Block => 1 << y,
+        }
+    }
+
+    fn char(&self, bits: u8) -> char {
+        match self {
+            Charset::Braille => char::from_u32(0x2800 + bits as u32).unwrap(),
+            Charset::Block => [' ', '▀', '▄', '█'][bits as usize],
+        }
+    }
+}
+
+///
+/// Options for [`Stage3::render_term`].
+///
+#[derive(Copy, Clone, Debug)]
+pub struct TermOptions {
+    size: [usize; 2],
+    charset: Charset,
+    color: bool,
+}
+
+impl Default for TermOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TermOptions {
+    ///
+    /// A plot area of 60x16 characters drawn with colored braille.
+    ///
+    pub fn new() -> Self {
+        TermOptions {
+            size: [60, 16],
+            charset: Charset::Braille,
+            color: true,
+        }
+    }
+
+    ///
+    /// The width and height of the plot area in characters, not including the axis labels and legend.
+    ///
+    pub fn with_size(mut self, size: [usize; 2]) -> Self {
+        self.size = [size[0].max(1), size[1].max(1)];
+        self
+    }
+
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    ///
+    /// Color each plot using ANSI escape codes. Turn this off when the output is not a terminal.
+    ///
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+//The ANSI colors of the plots, in the same order as the colors of the light theme.
+const COLORS: [u8; 8] = [34, 31, 32, 33, 36, 92, 91, 35];
+
+fn ansi(color: Option<usize>) -> String {
+    match color {
+        Some(i) => format!("\x1b[{}m", COLORS[i % COLORS.len()]),
+        None => "\x1b[0m".to_string(),
+    }
+}
+
+///
+/// The characters of the plot area. Each character remembers the plot that last drew in it.
+///
+struct Canvas {
+    charset: Charset,
+    size: [usize; 2],
+    cells: Vec<(u8, Option<usize>)>,
+}
+
+impl Canvas {
+    fn new(size: [usize; 2], charset: Charset) -> Self {
+        Canvas {
+            charset,
+            size,
+            cells: vec![(0, None); size[0] * size[1]],
+        }
+    }
+
+    //The number of dots across and down.
+    fn dots(&self) -> [usize; 2] {
+        let [dx, dy] = self.charset.dots();
+        [self.size[0] * dx, self.size[1] * dy]
+    }
+
+    fn set(&mut self, [x, y]: [f64; 2], color: usize) {
+        let [w, h] = self.dots();
+        if !(x.is_finite() && y.is_finite()) {
+            return;
+        }
+        let (x, y) = (x.round(), y.round());
+        if x < 0.0 || y < 0.0 || x >= w as f64 || y >= h as f64 {
+            return;
+        }
+        let (x, y) = (x as usize, y as usize);
+        let [dx, dy] = self.charset.dots();
+        let cell = &mut self.cells[(y / dy) * self.size[0] + x / dx];
+        cell.0 |= self.charset.bit([x % dx, y % dy]);
+        cell.1 = Some(color);
+    }
+
+    fn line(&mut self, [x1, y1]: [f64; 2], [x2, y2]: [f64; 2], color: usize) {
+        if ![x1, y1, x2, y2].iter().all(|a| a.is_finite()) {
+            return;
+        }
+        let steps = (x2 - x1).abs().max((y2 - y1).abs()).ceil().max(1.0);
+        //Don't spend forever on a line that is mostly off the canvas.
+        let steps = steps.min(((self.dots()[0] + self.dots()[1]) * 4) as f64);
+        let n = steps as usize;
+        for i in 0..=n {
+            let t = i as f64 / steps;
+            self.set([x1 + (x2 - x1) * t, y1 + (y2 - y1) * t], color);
+        }
+    }
+
+    //Fill the column from the point down to the bottom of the canvas.
+    fn column(&mut self, [x, y]: [f64; 2], color: usize) {
+        if !(x.is_finite() && y.is_finite()) {
+            return;
+        }
+        let bottom = self.dots()[1] as f64 - 1.0;
+        let mut yy = y.max(0.0).round();
+        while yy <= bottom {
+            self.set([x, yy], color);
+            yy += 1.0;
+        }
+    }
+
+    fn row(&self, y: usize, color: bool, w: &mut dyn fmt::Write) -> fmt::Result {
+        let mut current = None;
+        for &(bits, c) in &self.cells[y * self.size[0]..(y + 1) * self.size[0]] {
+            let c = if bits == 0 { None } else { c };
+            if color && c != current {
+                w.write_str(&ansi(c))?;
+                current = c;
+            }
+            w.write_char(self.charset.char(bits))?;
+        }
+        if color && current.is_some() {
+            w.write_str(&ansi(None))?;
+        }
+        Ok(())
+    }
+}
+
+impl<P, A, B, BB> Stage3<P, A, B, BB>
+where
+    P: PlotIterator,
+    A: crate::ticks::TickDist<Num = P::X>,
+    B: crate::ticks::TickDist<Num = P::Y>,
+    BB: BaseFmt,
+{
+    ///
+    /// Draw the plots as text instead of svg. See the [`term`](crate::render::term) module.
+    ///
+    /// Polar plots are drawn as if they were not polar.
+    ///
+    pub fn render_term<T: fmt::Write>(self, opt: &TermOptions, mut w: T) -> fmt::Result {
+        let Stage3 {
+            data:
+                Stage2 {
+                    xticks,
+                    yticks,
+                    mut plots,
+                    boundx,
+                    boundy,
+                    ..
+                },
+            mut base,
+            ..
+        } = self;
+
+        let mut canvas = Canvas::new(opt.size, opt.charset);
+        let [width, height] = canvas.dots();
+
+        let rangex = [boundx.min, boundx.max];
+        let rangey = [boundy.min, boundy.max];
+
+        //The position in dots, with the y axis pointing down.
+        let maxx = (width - 1) as f64;
+        let maxy = (height - 1) as f64;
+        let to_dots = |(x, y): (P::X, P::Y)| {
+            [
+                x.scale(rangex, maxx) - boundx.min.scale(rangex, maxx),
+                maxy - (y.scale(rangey, maxy) - boundy.min.scale(rangey, maxy)),
+            ]
+        };
+
+        let mut legend = Vec::new();
+        let mut colori = 0;
+        let mut f = crate::build::RenderablePlotIter::new(&mut plots);
+        while let Some(mut ppp) = f.next_plot() {
+            let typ = ppp.typ();
+
+            let mut name = String::new();
+            ppp.name(&mut name).unwrap()?;
+
+            let p_type = match typ {
+                PlotMetaType::Text => {
+                    ppp.plots().for_each(drop);
+                    if !name.is_empty() {
+                        legend.push((name, None));
+                    }
+                    continue;
+                }
+                PlotMetaType::Plot(p_type) => p_type,
+            };
+
+            let points: Vec<_> = ppp.plots().map(to_dots).collect();
+
+            match p_type {
+                PlotType::Line => {
+                    for p in points.windows(2) {
+                        canvas.line(p[0], p[1], colori);
+                    }
+                    if let [p] = points[..] {
+                        canvas.set(p, colori);
+                    }
+                }
+                PlotType::Histo | PlotType::LineFill | PlotType::LineFillRaw => {
+                    for p in points.windows(2) {
+                        let [x1, x2] = [p[0][0], p[1][0]];
+                        let mut x = x1.round();
+                        while x <= x2.round() {
+                            //Histograms are flat between points, line fills are sloped.
+                            let y = if let PlotType::Histo = p_type {
+                                p[0][1]
+                            } else {
+                                p[0][1] + (p[1][1] - p[0][1]) * (x - x1) / (x2 - x1).max(1.0)
+                            };
+                            canvas.column([x, y], colori);
+                            x += 1.0;
+                        }
+                    }
+                }
+                PlotType::Bars => {
+                    //Bars are horizontal, so fill the row from the y axis to the point.
+                    for &[x, y] in points.iter() {
+                        canvas.line([0.0, y], [x, y], colori);
+                    }
+                }
+                PlotType::Reference | PlotType::Annotation => continue,
+                _ => {
+                    for &p in points.iter() {
+                        canvas.set(p, colori);
+                    }
+                }
+            }
+
+            if !name.is_empty() {
+                legend.push((name, Some(colori)));
+            }
+            colori += 1;
+        }
+
+        let [cols, rows] = opt.size;
+
+        let yticks = yticks.unwrap();
+        let mut yfmt = yticks.fmt;
+        let mut ylabels: Vec<Option<String>> = vec![None; rows];
+        for val in yticks
+            .iter
+            .into_iter()
+            .skip_while(|&y| y < boundy.min)
+            .take_while(|&y| y <= boundy.max)
+        {
+            let [_, y] = to_dots((boundx.min, val));
+            let row = (y / opt.charset.dots()[1] as f64)
+                .round()
+                .min((rows - 1) as f64) as usize;
+            let mut s = String::new();
+            yfmt.write_tick(&mut s, &val)?;
+            ylabels[row] = Some(s);
+        }
+
+        let xticks = xticks.unwrap();
+        let mut xfmt = xticks.fmt;
+        let mut xlabels = Vec::new();
+        for val in xticks
+            .iter
+            .into_iter()
+            .skip_while(|&x| x < boundx.min)
+            .take_while(|&x| x <= boundx.max)
+        {
+            let [x, _] = to_dots((val, boundy.min));
+            let col = (x / opt.charset.dots()[0] as f64)
+                .round()
+                .min((cols - 1) as f64) as usize;
+            let mut s = String::new();
+            xfmt.write_tick(&mut s, &val)?;
+            xlabels.push((col, s));
+        }
+
+        let indent = ylabels
+            .iter()
+            .flatten()
+            .map(|s| s.chars().count())
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let mut title = String::new();
+        base.write_title(&mut title)?;
+        let mut xname = String::new();
+        base.write_xname(&mut xname)?;
+        xfmt.write_where(&mut xname)?;
+        let mut yname = String::new();
+        base.write_yname(&mut yname)?;
+        yfmt.write_where(&mut yname)?;
+
+        let centered = |w: &mut T, text: &str| -> fmt::Result {
+            for line in text.lines() {
+                let pad = (indent + 1 + cols).saturating_sub(line.chars().count()) / 2;
+                writeln!(w, "{:pad$}{}", "", line, pad = pad)?;
+            }
+            Ok(())
+        };
+
+        centered(&mut w, &title)?;
+        for line in yname.lines() {
+            writeln!(w, "{}", line)?;
+        }
+
+        for (row, label) in ylabels.iter().enumerate() {
+            let (label, axis) = match label {
+                Some(label) => (label.as_str(), '┤'),
+                None => ("", '│'),
+            };
+            write!(w, "{:>width$}{}", label, axis, width = indent)?;
+            canvas.row(row, opt.color, &mut w)?;
+            writeln!(w)?;
+        }
+
+        //The bottom axis with a mark at each tick.
+        let mut axis = vec!['─'; cols];
+        for &(col, _) in xlabels.iter() {
+            axis[col] = '┬';
+        }
+        writeln!(
+            w,
+            "{:>width$}{}",
+            '└',
+            axis.into_iter().collect::<String>(),
+            width = indent + 1
+        )?;
+
+        //Center each label under its tick, skipping any that would overlap the previous one.
+        let mut line = String::new();
+        let mut used = 0;
+        for (col, label) in xlabels {
+            let len = label.chars().count();
+            let start = (indent + 1 + col).saturating_sub(len / 2);
+            if start < used + 1 && used != 0 {
+                continue;
+            }
+            line += &" ".repeat(start - used);
+            line += &label;
+            used = start + len;
+        }
+        writeln!(w, "{}", line)?;
+
+        centered(&mut w, &xname)?;
+
+        for (name, color) in legend {
+            let name = name.lines().next().unwrap_or("");
+            match color {
+                Some(c) if opt.color => writeln!(w, "{}●{} {}", ansi(Some(c)), ansi(None), name)?,
+                Some(_) => writeln!(w, "● {}", name)?,
+                None => writeln!(w, "  {}", name)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Draw the plots as text into a string. See [`Stage3::render_term`].
+    ///
+    pub fn render_term_string(self, opt: &TermOptions) -> Result<String, fmt::Error> {
+        let mut s = String::new();
+        self.render_term(opt, &mut s)?;
+        Ok(s)
+    }
+}
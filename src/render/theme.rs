@@ -0,0 +1,494 @@
+//!
+//! Build a css theme programmatically
+//!
+//! The generated css uses the same `poloto_*` classes as [`Theme::light`] and [`Theme::dark`],
+//! so a [`ThemeBuilder`] started from one of the presets renders the exact same css string.
+//!
+//! A theme can also read its colors from css custom properties (see [`ThemeBuilder::with_css_variables`]),
+//! and switch to a dark theme when the page prefers a dark color scheme (see [`ThemeBuilder::with_dark_mode`]).
+//!
+use super::*;
+use crate::build::style::Dash;
+
+///
+/// Build a custom css theme, starting from the light or dark preset.
+///
+/// ```
+/// use poloto::build::plot;
+/// use poloto::render::theme::ThemeBuilder;
+///
+/// let css = ThemeBuilder::dark()
+///     .with_background("black")
+///     .with_palette(["tomato", "gold", "skyblue"])
+///     .css();
+///
+/// let svg = poloto::data(poloto::plots!(plot("a").line().cloned([[1, 80], [2, 90]].iter())))
+///     .build_and_label(("title", "x", "y"))
+///     .append_to(poloto::header().custom_theme(poloto::render::Theme::new(&css)))
+///     .render_string();
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeBuilder {
+    font_family: String,
+    font_size: usize,
+    title_font_size: usize,
+    name_font_size: usize,
+    legend_font_size: Option<usize>,
+    background: String,
+    text_color: String,
+    axis_color: String,
+    tick_line_color: String,
+    axis_line_width: f64,
+    tick_line_width: f64,
+    line_width: f64,
+    scatter_size: f64,
+    palette: Vec<String>,
+    dashes: Vec<Dash>,
+    css_variables: bool,
+    dark_mode: Option<Box<ThemeBuilder>>,
+}
+
+impl Default for ThemeBuilder {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+///
+/// Built in series colors. Pass them to [`ThemeBuilder::with_palette`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Palette {
+    /// The 8 colors of the light and dark presets.
+    Default,
+    /// The 8 colorblind safe colors by Okabe and Ito, with black last.
+    OkabeIto,
+    /// The 10 colors of Tableau.
+    Tableau10,
+    /// The ColorBrewer Set1 qualitative palette of 9 colors.
+    Set1,
+    /// The ColorBrewer Set2 qualitative palette of 8 colors.
+    Set2,
+    /// The ColorBrewer Set3 qualitative palette of 12 colors.
+    Set3,
+    /// The ColorBrewer Dark2 qualitative palette of 8 colors.
+    Dark2,
+    /// The ColorBrewer Paired qualitative palette of 12 colors.
+    Paired,
+    /// The perceptually uniform viridis color map. It can be sampled into any number of colors.
+    Viridis,
+}
+
+const DEFAULT_PALETTE: &[&str] = &[
+    "blue",
+    "red",
+    "green",
+    "gold",
+    "aqua",
+    "lime",
+    "orange",
+    "chocolate",
+];
+
+const OKABE_ITO: &[&str] = &[
+    "#E69F00", "#56B4E9", "#009E73", "#F0E442", "#0072B2", "#D55E00", "#CC79A7", "#000000",
+];
+
+const TABLEAU10: &[&str] = &[
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+
+const SET1: &[&str] = &[
+    "#e41a1c", "#377eb8", "#4daf4a", "#984ea3", "#ff7f00", "#ffff33", "#a65628", "#f781bf",
+    "#999999",
+];
+
+const SET2: &[&str] = &[
+    "#66c2a5", "#fc8d62", "#8da0cb", "#e78ac3", "#a6d854", "#ffd92f", "#e5c494", "#b3b3b3",
+];
+
+const SET3: &[&str] = &[
+    "#8dd3c7", "#ffffb3", "#bebada", "#fb8072", "#80b1d3", "#fdb462", "#b3de69", "#fccde5",
+    "#d9d9d9", "#bc80bd", "#ccebc5", "#ffed6f",
+];
+
+const DARK2: &[&str] = &[
+    "#1b9e77", "#d95f02", "#7570b3", "#e7298a", "#66a61e", "#e6ab02", "#a6761d", "#666666",
+];
+
+const PAIRED: &[&str] = &[
+    "#a6cee3", "#1f78b4", "#b2df8a", "#33a02c", "#fb9a99", "#e31a1c", "#fdbf6f", "#ff7f00",
+    "#cab2d6", "#6a3d9a", "#ffff99", "#b15928",
+];
+
+//Evenly spaced stops of the viridis color map.
+const VIRIDIS: [[u8; 3]; 9] = [
+    [0x44, 0x01, 0x54],
+    [0x47, 0x2c, 0x7a],
+    [0x3b, 0x51, 0x8b],
+    [0x2c, 0x71, 0x8e],
+    [0x21, 0x90, 0x8d],
+    [0x27, 0xad, 0x81],
+    [0x5c, 0xc8, 0x63],
+    [0xaa, 0xdc, 0x32],
+    [0xfd, 0xe7, 0x25],
+];
+
+pub(crate) fn viridis(t: f64) -> String {
+    let pos = t.clamp(0.0, 1.0) * (VIRIDIS.len() - 1) as f64;
+    let i = (pos.floor() as usize).min(VIRIDIS.len() - 2);
+    let frac = pos - i as f64;
+
+    let [a, b] = [VIRIDIS[i], VIRIDIS[i + 1]];
+    let c: Vec<_> = (0..3)
+        .map(|k| (a[k] as f64 + (b[k] as f64 - a[k] as f64) * frac).round() as u8)
+        .collect();
+    format!("#{:02x}{:02x}{:02x}", c[0], c[1], c[2])
+}
+
+impl Palette {
+    fn discrete(&self) -> Option<&'static [&'static str]> {
+        Some(match self {
+            Palette::Default => DEFAULT_PALETTE,
+            Palette::OkabeIto => OKABE_ITO,
+            Palette::Tableau10 => TABLEAU10,
+            Palette::Set1 => SET1,
+            Palette::Set2 => SET2,
+            Palette::Set3 => SET3,
+            Palette::Dark2 => DARK2,
+            Palette::Paired => PAIRED,
+            Palette::Viridis => return None,
+        })
+    }
+
+    ///
+    /// The colors of the palette. Viridis is sampled into 8 colors.
+    ///
+    pub fn colors(&self) -> Vec<String> {
+        match self.discrete() {
+            Some(colors) => colors.iter().map(|c| c.to_string()).collect(),
+            None => self.sample(8),
+        }
+    }
+
+    ///
+    /// Exactly `num` colors of the palette. Viridis is sampled evenly from end to end,
+    /// the other palettes repeat their colors if `num` is larger than the palette.
+    ///
+    pub fn sample(&self, num: usize) -> Vec<String> {
+        match self.discrete() {
+            Some(colors) => colors
+                .iter()
+                .cycle()
+                .take(num)
+                .map(|c| c.to_string())
+                .collect(),
+            None => (0..num)
+                .map(|i| viridis(i as f64 / (num.max(2) - 1) as f64))
+                .collect(),
+        }
+    }
+}
+
+impl ThemeBuilder {
+    ///
+    /// The settings of [`Theme::light`].
+    ///
+    pub fn light() -> Self {
+        ThemeBuilder {
+            font_family: "Roboto,sans-serif".to_string(),
+            font_size: 16,
+            title_font_size: 24,
+            name_font_size: 24,
+            legend_font_size: Some(20),
+            background: "AliceBlue".to_string(),
+            text_color: "black".to_string(),
+            axis_color: "black".to_string(),
+            tick_line_color: "gray".to_string(),
+            axis_line_width: 3.0,
+            tick_line_width: 0.5,
+            line_width: 2.0,
+            scatter_size: 7.0,
+            palette: Palette::Default.colors(),
+            dashes: Vec::new(),
+            css_variables: false,
+            dark_mode: None,
+        }
+    }
+
+    ///
+    /// The settings of [`Theme::dark`].
+    ///
+    pub fn dark() -> Self {
+        ThemeBuilder {
+            legend_font_size: None,
+            background: "#262626".to_string(),
+            text_color: "white".to_string(),
+            axis_color: "white".to_string(),
+            tick_line_color: "dimgray".to_string(),
+            ..Self::light()
+        }
+    }
+
+    pub fn with_font_family(mut self, font_family: &str) -> Self {
+        self.font_family = font_family.to_string();
+        self
+    }
+
+    ///
+    /// The font size of the tick labels and any other text without its own size.
+    ///
+    pub fn with_font_size(mut self, size: usize) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    pub fn with_title_font_size(mut self, size: usize) -> Self {
+        self.title_font_size = size;
+        self
+    }
+
+    ///
+    /// The font size of the x and y axis names.
+    ///
+    pub fn with_name_font_size(mut self, size: usize) -> Self {
+        self.name_font_size = size;
+        self
+    }
+
+    ///
+    /// The font size of the legend. If `None`, the legend uses the default font size.
+    ///
+    pub fn with_legend_font_size(mut self, size: Option<usize>) -> Self {
+        self.legend_font_size = size;
+        self
+    }
+
+    pub fn with_background(mut self, color: &str) -> Self {
+        self.background = color.to_string();
+        self
+    }
+
+    pub fn with_text_color(mut self, color: &str) -> Self {
+        self.text_color = color.to_string();
+        self
+    }
+
+    pub fn with_axis_color(mut self, color: &str) -> Self {
+        self.axis_color = color.to_string();
+        self
+    }
+
+    pub fn with_tick_line_color(mut self, color: &str) -> Self {
+        self.tick_line_color = color.to_string();
+        self
+    }
+
+    pub fn with_axis_line_width(mut self, width: f64) -> Self {
+        self.axis_line_width = width;
+        self
+    }
+
+    pub fn with_tick_line_width(mut self, width: f64) -> Self {
+        self.tick_line_width = width;
+        self
+    }
+
+    pub fn with_line_width(mut self, width: f64) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    pub fn with_scatter_size(mut self, size: f64) -> Self {
+        self.scatter_size = size;
+        self
+    }
+
+    ///
+    /// The colors of the plots. The nth color styles the `poloto{n}stroke` and `poloto{n}fill` classes,
+    /// so one rule of each is emitted per color. Start the render options with [`ThemeBuilder::render_opt`]
+    /// so that the plots wrap around after the last color.
+    ///
+    /// ```
+    /// use poloto::render::theme::{Palette, ThemeBuilder};
+    /// let theme = ThemeBuilder::light().with_palette(Palette::Viridis.sample(12));
+    /// let opt = theme.render_opt().with_tick_lines([true, true]).move_into();
+    /// ```
+    ///
+    pub fn with_palette<I: IntoIterator>(mut self, colors: I) -> Self
+    where
+        I::Item: Display,
+    {
+        self.palette = colors.into_iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    ///
+    /// The dash patterns of the lines. The nth plot gets the nth pattern, styling the
+    /// `poloto{n}dash` class. The patterns repeat if there are more colors than patterns.
+    /// By default, no dash patterns are emitted.
+    ///
+    /// ```
+    /// use poloto::build::style::Dash;
+    /// use poloto::render::theme::ThemeBuilder;
+    /// let css = ThemeBuilder::light().with_dashes(Dash::ALL).css();
+    /// assert!(css.contains(".poloto1dash{stroke-dasharray:10 6;}"));
+    /// ```
+    ///
+    pub fn with_dashes<I: IntoIterator<Item = Dash>>(mut self, dashes: I) -> Self {
+        self.dashes = dashes.into_iter().collect();
+        self
+    }
+
+    ///
+    /// Read the colors and the font family from css custom properties, falling back to the colors of this theme.
+    /// This lets a page that embeds the svg re-theme it by setting the properties on any parent element.
+    ///
+    /// The properties are `--poloto-bg`, `--poloto-text`, `--poloto-axis`, `--poloto-tick-line`,
+    /// `--poloto-font-family` and `--poloto-color-0`, `--poloto-color-1`, ... for the palette.
+    ///
+    /// ```
+    /// use poloto::render::theme::ThemeBuilder;
+    /// let css = ThemeBuilder::light().with_css_variables(true).css();
+    /// assert!(css.contains(".poloto_background{fill:var(--poloto-bg,AliceBlue);}"));
+    /// assert!(css.contains(".poloto0stroke{stroke:var(--poloto-color-0,blue);}"));
+    /// ```
+    ///
+    pub fn with_css_variables(mut self, css_variables: bool) -> Self {
+        self.css_variables = css_variables;
+        self
+    }
+
+    ///
+    /// Switch to the `dark` theme inside of a `prefers-color-scheme: dark` media query.
+    /// Whether the dark theme reads css custom properties is decided by this theme.
+    ///
+    /// ```
+    /// use poloto::render::theme::ThemeBuilder;
+    /// let css = ThemeBuilder::light().with_dark_mode(ThemeBuilder::dark()).css();
+    /// assert!(css.contains("@media (prefers-color-scheme:dark){"));
+    /// ```
+    ///
+    pub fn with_dark_mode(mut self, dark: ThemeBuilder) -> Self {
+        self.dark_mode = Some(Box::new(dark));
+        self
+    }
+
+    pub fn palette(&self) -> &[String] {
+        &self.palette
+    }
+
+    ///
+    /// The number of `poloto{n}stroke` and `poloto{n}fill` classes the css styles.
+    ///
+    pub fn num_css_classes(&self) -> usize {
+        self.palette.len()
+    }
+
+    ///
+    /// Render options that use as many css classes as the palette has colors.
+    /// Use them instead of [`render_opt`](crate::render::render_opt) to render plots with this theme.
+    ///
+    pub fn render_opt(&self) -> RenderOptions {
+        let mut opt = RenderOptions::new();
+        opt.num_css_class(Some(self.num_css_classes()));
+        opt
+    }
+
+    ///
+    /// Render the theme into a css string. Wrap it in a [`Theme`] using [`Theme::new`].
+    ///
+    pub fn css(&self) -> String {
+        let mut s = String::new();
+        self.write_css(&mut s).unwrap();
+        s
+    }
+
+    fn write_css(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        self.write_rules(w, self.css_variables)?;
+
+        if let Some(dark) = &self.dark_mode {
+            write!(w, "@media (prefers-color-scheme:dark){{")?;
+            dark.write_rules(w, self.css_variables)?;
+            write!(w, "}}")?;
+        }
+        Ok(())
+    }
+
+    fn write_rules(&self, w: &mut impl fmt::Write, css_variables: bool) -> fmt::Result {
+        let var = |name: &str, value: &str| {
+            if css_variables {
+                format!("var(--poloto-{},{})", name, value)
+            } else {
+                value.to_string()
+            }
+        };
+
+        write!(
+            w,
+            ".poloto{{\
+            stroke-linecap:round;\
+            stroke-linejoin:round;\
+            font-family:{};\
+            font-size:{}px;\
+            }}\
+            .poloto_background{{fill:{};}}\
+            .poloto_scatter{{stroke-width:{}}}\
+            .poloto_tick_line{{stroke:{};stroke-width:{}}}\
+            .poloto_line{{stroke-width:{}}}\
+            .poloto_text{{fill: {};}}\
+            .poloto_axis_lines{{stroke: {};stroke-width:{};fill:none;stroke-dasharray:none}}\
+            .poloto_title{{font-size:{}px;dominant-baseline:start;text-anchor:middle;}}\
+            .poloto_xname{{font-size:{}px;dominant-baseline:start;text-anchor:middle;}}\
+            .poloto_yname{{font-size:{}px;dominant-baseline:start;text-anchor:middle;}}",
+            var("font-family", &self.font_family),
+            self.font_size,
+            var("bg", &self.background),
+            self.scatter_size,
+            var("tick-line", &self.tick_line_color),
+            self.tick_line_width,
+            self.line_width,
+            var("text", &self.text_color),
+            var("axis", &self.axis_color),
+            self.axis_line_width,
+            self.title_font_size,
+            self.name_font_size,
+            self.name_font_size,
+        )?;
+
+        if let Some(size) = self.legend_font_size {
+            write!(
+                w,
+                ".poloto_legend_text{{font-size:{}px;dominant-baseline:middle;text-anchor:start;}}",
+                size
+            )?;
+        }
+
+        let colors: Vec<_> = self
+            .palette
+            .iter()
+            .enumerate()
+            .map(|(i, color)| var(&format!("color-{}", i), color))
+            .collect();
+
+        for (i, color) in colors.iter().enumerate() {
+            write!(w, ".poloto{}stroke{{stroke:{};}}", i, color)?;
+        }
+        for (i, color) in colors.iter().enumerate() {
+            write!(w, ".poloto{}fill{{fill:{};}}", i, color)?;
+        }
+        if !self.dashes.is_empty() {
+            for (i, dash) in (0..self.palette.len()).zip(self.dashes.iter().cycle()) {
+                write!(
+                    w,
+                    ".poloto{}dash{{stroke-dasharray:{};}}",
+                    i,
+                    dash.dasharray()
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,503 @@
+//!
+//! Export plots as a [pgfplots](https://ctan.org/pkg/pgfplots) axis for LaTeX documents.
+//!
+//! The plots are drawn by LaTeX, so the text uses the fonts and sizes of the document.
+//! The colors come from the document's pgfplots cycle list.
+//!
+//! The coordinates are scaled so that both axes go from `0` to `1`. The ticks are placed
+//! and labeled using poloto's tick generators, so the labels show the values of the data.
+//! Titles, names and tick labels are written as they are, so they can contain LaTeX
+//! such as `$\alpha$`, but characters like `%`, `&` and `#` have to be escaped.
+//!
+//! ```
+//! let data = poloto::data(poloto::build::plot("sin").line().buffered(
+//!     (0..50).map(|i| [i as f64, (i as f64 / 8.0).sin()]),
+//! ));
+//!
+//! let tex = data
+//!     .build_and_label(("sine", "x", "y"))
+//!     .render_tikz_string(&poloto::render::tikz::TikzOptions::new())
+//!     .unwrap();
+//!
+//! assert!(tex.contains("\\begin{axis}"));
+//! ```
+//!
+use super::render_plot::{hexbin_center, hexbin_count};
+use super::*;
+use crate::build::style::{Dash, MarkerShape, SeriesStyle};
+use crate::build::waterfall::WaterfallPart;
+use crate::build::{hexbin, PlotMetaType, PlotType};
+use crate::ticks::tick_fmt::TickFmt;
+
+///
+/// Options for [`Stage3::render_tikz`].
+///
+#[derive(Clone, Debug)]
+pub struct TikzOptions {
+    width: Option<String>,
+    height: Option<String>,
+    picture: bool,
+}
+
+impl Default for TikzOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TikzOptions {
+    ///
+    /// Use the default size of pgfplots, and wrap the axis in a `tikzpicture` environment.
+    ///
+    pub fn new() -> Self {
+        TikzOptions {
+            width: None,
+            height: None,
+            picture: true,
+        }
+    }
+
+    ///
+    /// The width of the axis as a LaTeX length, for example `\linewidth` or `8cm`.
+    ///
+    pub fn with_width(mut self, width: &str) -> Self {
+        self.width = Some(width.to_string());
+        self
+    }
+
+    ///
+    /// The height of the axis as a LaTeX length.
+    ///
+    pub fn with_height(mut self, height: &str) -> Self {
+        self.height = Some(height.to_string());
+        self
+    }
+
+    ///
+    /// Whether to wrap the axis in a `tikzpicture` environment.
+    /// Turn this off to put the axis in a `tikzpicture` of your own.
+    ///
+    pub fn with_picture(mut self, picture: bool) -> Self {
+        self.picture = picture;
+        self
+    }
+}
+
+//Numbers with trailing zeros removed, or `nan` so that pgfplots leaves a gap.
+fn num(a: f64) -> String {
+    if !a.is_finite() {
+        return "nan".to_string();
+    }
+    let s = format!("{:.5}", a);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+//Multiple lines of text are separated using `\\`.
+fn text(s: &str) -> String {
+    s.trim_end_matches('\n').replace('\n', "\\\\")
+}
+
+fn style_options(style: SeriesStyle, opts: &mut Vec<String>) {
+    if let Some(dash) = style.dash {
+        let dash = match dash {
+            Dash::Solid => "solid",
+            Dash::Dashed => "dashed",
+            Dash::Dotted => "dotted",
+            Dash::DashDot => "dashdotted",
+            Dash::LongDash => "loosely dashed",
+        };
+        opts.push(dash.to_string());
+    }
+    if let Some(width) = style.width {
+        opts.push(format!("line width={}pt", num(width / 2.0)));
+    }
+}
+
+fn coordinates(it: impl Iterator<Item = [f64; 2]>) -> Vec<String> {
+    it.map(|[x, y]| format!("({},{})", num(x), num(y)))
+        .collect()
+}
+
+//Pairs of points are drawn as separate line segments.
+fn segments(mut it: impl Iterator<Item = [f64; 2]>) -> Vec<String> {
+    let mut points = Vec::new();
+    while let (Some(a), Some(b)) = (it.next(), it.next()) {
+        points.extend(coordinates([a, b, [f64::NAN, f64::NAN]].into_iter()));
+    }
+    points
+}
+
+//Pairs of points are the left and right of a bar that doesn't have to start at zero.
+//Each bar is a closed rectangle of the given height in axis units.
+fn bars(mut it: impl Iterator<Item = [f64; 2]>, height: f64) -> Vec<String> {
+    let mut points = Vec::new();
+    while let (Some([x1, y1]), Some([x2, _])) = (it.next(), it.next()) {
+        let [bottom, top] = [y1 - height / 2.0, y1 + height / 2.0];
+        points.extend(coordinates(
+            [
+                [x1, bottom],
+                [x2, bottom],
+                [x2, top],
+                [x1, top],
+                [x1, bottom],
+                [f64::NAN, f64::NAN],
+            ]
+            .into_iter(),
+        ));
+    }
+    points
+}
+
+//Pairs of points are the start and the end of an arrow.
+fn arrows(mut it: impl Iterator<Item = [f64; 2]>) -> Vec<String> {
+    let mut rows = vec!["x y u v".to_string()];
+    while let (Some([x1, y1]), Some([x2, y2])) = (it.next(), it.next()) {
+        let finite = [x1, y1, x2, y2].iter().all(|a| a.is_finite());
+
+        //Zero length arrows have no direction to point the arrowhead in.
+        if finite && (x1 != x2 || y1 != y2) {
+            rows.push(format!(
+                "{} {} {} {}",
+                num(x1),
+                num(y1),
+                num(x2 - x1),
+                num(y2 - y1)
+            ));
+        }
+    }
+    rows
+}
+
+fn mark(marker: Option<MarkerShape>) -> &'static str {
+    match marker {
+        None => "mark=none",
+        Some(MarkerShape::Circle) => "mark=*",
+        Some(MarkerShape::Square) => "mark=square*",
+        Some(MarkerShape::Triangle) => "mark=triangle*",
+        Some(MarkerShape::Diamond) => "mark=diamond*",
+    }
+}
+
+impl<P, A, B, BB> Stage3<P, A, B, BB>
+where
+    P: PlotIterator,
+    A: crate::ticks::TickDist<Num = P::X>,
+    B: crate::ticks::TickDist<Num = P::Y>,
+    BB: BaseFmt,
+{
+    ///
+    /// Write a pgfplots axis instead of svg. See the [`tikz`](crate::render::tikz) module.
+    ///
+    /// Polar plots are drawn as if they were not polar. Reference lines and annotations are not drawn.
+    /// Hexbin plots are written as a scatter plot of the hexagon centers with the counts as point meta,
+    /// quiver plots use the pgfplots `quiver` handler, and waterfall bars are drawn as filled rectangles
+    /// that are half as tall as the space between two y ticks.
+    ///
+    pub fn render_tikz<T: fmt::Write>(self, opt: &TikzOptions, mut w: T) -> fmt::Result {
+        let Stage3 {
+            data:
+                Stage2 {
+                    xticks,
+                    yticks,
+                    mut plots,
+                    opt: opt_res,
+                    boundx,
+                    boundy,
+                    ..
+                },
+            mut base,
+            ..
+        } = self;
+
+        let rangex = [boundx.min, boundx.max];
+        let rangey = [boundy.min, boundy.max];
+
+        let to_unit = |(x, y): (P::X, P::Y)| {
+            [
+                x.scale(rangex, 1.0) - boundx.min.scale(rangex, 1.0),
+                y.scale(rangey, 1.0) - boundy.min.scale(rangey, 1.0),
+            ]
+        };
+
+        let mut axis = vec![
+            "xmin=0".to_string(),
+            "xmax=1".to_string(),
+            "ymin=0".to_string(),
+            "ymax=1".to_string(),
+            "unbounded coords=jump".to_string(),
+        ];
+
+        if let Some(width) = &opt.width {
+            axis.push(format!("width={{{}}}", width));
+        }
+        if let Some(height) = &opt.height {
+            axis.push(format!("height={{{}}}", height));
+        }
+
+        let mut title = String::new();
+        base.write_title(&mut title)?;
+        let mut xname = String::new();
+        base.write_xname(&mut xname)?;
+        let mut yname = String::new();
+        base.write_yname(&mut yname)?;
+
+        let xticks = xticks.unwrap();
+        let mut xfmt = xticks.fmt;
+        let yticks = yticks.unwrap();
+        let mut yfmt = yticks.fmt;
+
+        xfmt.write_where(&mut xname)?;
+        yfmt.write_where(&mut yname)?;
+
+        if !title.is_empty() {
+            axis.push(format!("title={{{}}}", text(&title)));
+            axis.push("title style={align=center}".to_string());
+        }
+        if !xname.is_empty() {
+            axis.push(format!("xlabel={{{}}}", text(&xname)));
+        }
+        if !yname.is_empty() {
+            axis.push(format!("ylabel={{{}}}", text(&yname)));
+        }
+
+        let mut pos = Vec::new();
+        let mut labels = Vec::new();
+        for val in xticks
+            .iter
+            .into_iter()
+            .skip_while(|&x| x < boundx.min)
+            .take_while(|&x| x <= boundx.max)
+        {
+            let [x, _] = to_unit((val, boundy.min));
+            let mut s = String::new();
+            xfmt.write_tick(&mut s, &val)?;
+            pos.push(num(x));
+            labels.push(format!("{{{}}}", s));
+        }
+        axis.push(format!("xtick={{{}}}", pos.join(",")));
+        axis.push(format!("xticklabels={{{}}}", labels.join(",")));
+
+        let mut pos = Vec::new();
+        let mut labels = Vec::new();
+        let mut ystep = f64::INFINITY;
+        let mut last = None;
+        for val in yticks
+            .iter
+            .into_iter()
+            .skip_while(|&y| y < boundy.min)
+            .take_while(|&y| y <= boundy.max)
+        {
+            let [_, y] = to_unit((boundx.min, val));
+            let mut s = String::new();
+            yfmt.write_tick(&mut s, &val)?;
+            if let Some(last) = last {
+                ystep = ystep.min(y - last);
+            }
+            last = Some(y);
+            pos.push(num(y));
+            labels.push(format!("{{{}}}", s));
+        }
+        axis.push(format!("ytick={{{}}}", pos.join(",")));
+        axis.push(format!("yticklabels={{{}}}", labels.join(",")));
+
+        let minor: Vec<_> = xfmt
+            .minor_ticks()
+            .iter()
+            .filter(|&&x| x >= boundx.min && x <= boundx.max)
+            .map(|&x| num(to_unit((x, boundy.min))[0]))
+            .collect();
+        if !minor.is_empty() {
+            axis.push(format!("minor xtick={{{}}}", minor.join(",")));
+        }
+        let minor: Vec<_> = yfmt
+            .minor_ticks()
+            .iter()
+            .filter(|&&y| y >= boundy.min && y <= boundy.max)
+            .map(|&y| num(to_unit((boundx.min, y))[1]))
+            .collect();
+        if !minor.is_empty() {
+            axis.push(format!("minor ytick={{{}}}", minor.join(",")));
+        }
+
+        //Write the plots first, so we know whether a legend is needed.
+        let mut body = String::new();
+        let mut has_legend = false;
+        let mut f = crate::build::RenderablePlotIter::new(&mut plots);
+        while let Some(mut ppp) = f.next_plot() {
+            let typ = ppp.typ();
+
+            let mut name = String::new();
+            ppp.name(&mut name).unwrap()?;
+            let name = text(&name);
+
+            let p_type = match typ {
+                PlotMetaType::Text => {
+                    ppp.plots().for_each(drop);
+                    if !name.is_empty() {
+                        has_legend = true;
+                        body += "\\addlegendimage{empty legend}\n";
+                        body += &format!("\\addlegendentry{{{}}}\n", name);
+                    }
+                    continue;
+                }
+                PlotMetaType::Plot(p_type) => p_type,
+            };
+
+            let style = ppp.style();
+            let details = ppp.details();
+            let mut opts = Vec::new();
+            let mut end = "";
+            let mut table = false;
+            //Fused, so that reading past the end doesn't take points from the next plot.
+            let it = ppp.plots().fuse().map(to_unit);
+            let points = match p_type {
+                PlotType::Line => {
+                    opts.push(mark(style.marker).to_string());
+                    style_options(style, &mut opts);
+                    coordinates(it)
+                }
+                PlotType::Scatter => {
+                    opts.push("only marks".to_string());
+                    opts.push(mark(style.marker.or(Some(MarkerShape::Circle))).to_string());
+                    coordinates(it)
+                }
+                PlotType::Histo => {
+                    opts.push("ybar interval".to_string());
+                    opts.push("fill".to_string());
+                    opts.push("fill opacity=0.5".to_string());
+                    coordinates(it)
+                }
+                PlotType::LineFill => {
+                    opts.push("mark=none".to_string());
+                    opts.push("fill".to_string());
+                    opts.push("fill opacity=0.5".to_string());
+                    style_options(style, &mut opts);
+                    end = " \\closedcycle";
+                    coordinates(it)
+                }
+                PlotType::LineFillRaw => {
+                    opts.push("mark=none".to_string());
+                    opts.push("fill".to_string());
+                    opts.push("fill opacity=0.5".to_string());
+                    style_options(style, &mut opts);
+                    end = " -- cycle";
+                    coordinates(it)
+                }
+                PlotType::Bars => {
+                    opts.push("xbar".to_string());
+                    opts.push("fill".to_string());
+                    opts.push("mark=none".to_string());
+                    coordinates(it)
+                }
+                PlotType::Waterfall
+                    if matches!(details.waterfall, Some(WaterfallPart::Connector)) =>
+                {
+                    opts.push("mark=none".to_string());
+                    opts.push("black".to_string());
+                    segments(it)
+                }
+                PlotType::Waterfall => {
+                    //The steps of a waterfall are its y ticks, so a bar is half as tall as the space
+                    //between two ticks. Without two ticks, use the bar width of the svg.
+                    let height = if ystep.is_finite() && ystep > 0.0 {
+                        ystep / 2.0
+                    } else {
+                        opt_res.bar_width / opt_res.boundy.max
+                    };
+                    opts.push("mark=none".to_string());
+                    opts.push("fill".to_string());
+                    opts.push("area legend".to_string());
+                    bars(it, height)
+                }
+                PlotType::Quiver => {
+                    opts.push("quiver={u=\\thisrow{u},v=\\thisrow{v}}".to_string());
+                    opts.push("-stealth".to_string());
+                    opts.push("mark=none".to_string());
+                    table = true;
+                    arrows(it)
+                }
+                PlotType::Hexbin => {
+                    let size = details.hexbin_size.unwrap_or(hexbin::DEFAULT_SIZE);
+                    opts.push("scatter".to_string());
+                    opts.push("only marks".to_string());
+                    opts.push("mark=*".to_string());
+                    opts.push("point meta=explicit".to_string());
+                    opts.push("colormap/viridis".to_string());
+
+                    //Bin in the screen space of the svg, so the hexagons are the same.
+                    let [w, h] = [opt_res.boundx.max, opt_res.boundy.max];
+                    hexbin_count(it.map(|[x, y]| [x * w, y * h]), size)
+                        .into_iter()
+                        .map(|((q, r), count)| {
+                            let [x, y] = hexbin_center(q, r, size);
+                            format!("({},{}) [{}]", num(x / w), num(y / h), count)
+                        })
+                        .collect()
+                }
+                PlotType::Reference | PlotType::Annotation => {
+                    it.for_each(drop);
+                    continue;
+                }
+            };
+
+            //A plot without a name doesn't get a legend entry.
+            if name.is_empty() {
+                opts.push("forget plot".to_string());
+            }
+
+            if table {
+                body += &format!("\\addplot+[{}] table {{\n", opts.join(", "));
+                for line in points {
+                    body += &line;
+                    body += "\n";
+                }
+            } else {
+                body += &format!("\\addplot+[{}] coordinates {{\n", opts.join(", "));
+                for line in points.chunks(6) {
+                    body += &line.join(" ");
+                    body += "\n";
+                }
+            }
+            body += &format!("}}{};\n", end);
+
+            if !name.is_empty() {
+                has_legend = true;
+                body += &format!("\\addlegendentry{{{}}}\n", name);
+            }
+        }
+
+        if has_legend {
+            axis.push("legend pos=outer north east".to_string());
+            axis.push("legend cell align=left".to_string());
+        }
+
+        if opt.picture {
+            writeln!(w, "\\begin{{tikzpicture}}")?;
+        }
+        writeln!(w, "\\begin{{axis}}[")?;
+        for a in axis {
+            writeln!(w, "    {},", a)?;
+        }
+        writeln!(w, "]")?;
+        w.write_str(&body)?;
+        writeln!(w, "\\end{{axis}}")?;
+        if opt.picture {
+            writeln!(w, "\\end{{tikzpicture}}")?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Write a pgfplots axis into a string. See [`Stage3::render_tikz`].
+    ///
+    pub fn render_tikz_string(self, opt: &TikzOptions) -> Result<String, fmt::Error> {
+        let mut s = String::new();
+        self.render_tikz(opt, &mut s)?;
+        Ok(s)
+    }
+}
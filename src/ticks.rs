@@ -72,6 +72,14 @@ pub mod tick_fmt {
         fn write_where(&mut self, _: &mut dyn std::fmt::Write) -> std::fmt::Result {
             Ok(())
         }
+        ///
+        /// Positions between the ticks that are drawn without a label
+        /// if enabled with [`RenderOptions::with_minor_ticks`](crate::render::RenderOptions::with_minor_ticks).
+        /// There are none by default.
+        ///
+        fn minor_ticks(&mut self) -> Vec<Num> {
+            Vec::new()
+        }
     }
 
     pub struct WithWhereFmt<D, F> {
@@ -90,6 +98,9 @@ pub mod tick_fmt {
             let j = (self.func)();
             write!(w, "{}", j)
         }
+        fn minor_ticks(&mut self) -> Vec<N> {
+            self.ticks.minor_ticks()
+        }
     }
 
     pub struct WithTickFmt<D, F> {
@@ -107,6 +118,9 @@ pub mod tick_fmt {
         fn write_where(&mut self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
             self.ticks.write_where(w)
         }
+        fn minor_ticks(&mut self) -> Vec<N> {
+            self.ticks.minor_ticks()
+        }
     }
 
     pub struct WithData<K, E> {
@@ -121,6 +135,26 @@ pub mod tick_fmt {
         fn write_where(&mut self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
             self.ticks.write_where(w)
         }
+        fn minor_ticks(&mut self) -> Vec<N> {
+            self.ticks.minor_ticks()
+        }
+    }
+
+    pub struct WithMinorTicks<K, N> {
+        ticks: K,
+        minor: Vec<N>,
+    }
+
+    impl<N: Clone, K: TickFmt<N>> TickFmt<N> for WithMinorTicks<K, N> {
+        fn write_tick(&mut self, a: &mut dyn std::fmt::Write, val: &N) -> std::fmt::Result {
+            self.ticks.write_tick(a, val)
+        }
+        fn write_where(&mut self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+            self.ticks.write_where(w)
+        }
+        fn minor_ticks(&mut self) -> Vec<N> {
+            self.minor.clone()
+        }
     }
 
     impl<X: PlotNum, I: IntoIterator<Item = X>, Fmt: TickFmt<X>> TickDistribution<I, Fmt> {
@@ -163,6 +197,24 @@ pub mod tick_fmt {
             }
         }
 
+        ///
+        /// Specify the minor ticks. They are drawn without a label
+        /// if enabled with [`RenderOptions::with_minor_ticks`](crate::render::RenderOptions::with_minor_ticks).
+        ///
+        pub fn with_minor_ticks<J: IntoIterator<Item = X>>(
+            self,
+            minor: J,
+        ) -> TickDistribution<I, WithMinorTicks<Fmt, X>> {
+            TickDistribution {
+                iter: self.iter,
+                fmt: WithMinorTicks {
+                    ticks: self.fmt,
+                    minor: minor.into_iter().collect(),
+                },
+                res: self.res,
+            }
+        }
+
         pub fn with_fmt<J: TickFmt<I::Item>>(self, other: J) -> TickDistribution<I, J> {
             TickDistribution {
                 iter: self.iter,
@@ -0,0 +1,37 @@
+use super::*;
+use poloto::build::plot;
+
+#[test]
+fn annotation() -> fmt::Result {
+    let timings = [
+        (0, 21.0),
+        (1, 20.5),
+        (2, 21.2),
+        (3, 20.8),
+        (4, 27.9),
+        (5, 28.3),
+        (6, 27.7),
+        (7, 22.1),
+    ];
+
+    let plots = poloto::plots!(
+        plot("bench").line().buffered(timings.iter()),
+        plot("regression introduced here")
+            .annotate(4, 27.9)
+            .offset([-20.0, 30.0])
+            .arrow()
+            .highlight(),
+        plot("fixed").annotate(7, 22.1).offset([0.0, -15.0]),
+        plot("target")
+            .annotate(0, 15.0)
+            .offset([10.0, 0.0])
+            .in_area()
+    );
+
+    let w = util::create_test_file("annotation.svg");
+
+    poloto::data(plots)
+        .build_and_label(("benchmark", "commit", "time in ms"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
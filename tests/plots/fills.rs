@@ -0,0 +1,98 @@
+use super::*;
+
+use poloto::build::plot;
+use poloto::build::style::Fill;
+
+#[test]
+fn fills() -> fmt::Result {
+    let wave = |phase: f64, scale: f64| {
+        (0..40).map(move |i| {
+            let x = i as f64 / 4.0;
+            [x, ((x + phase).sin() + 1.5) * scale]
+        })
+    };
+
+    let data = poloto::data(poloto::plots!(
+        plot("fade")
+            .line_fill()
+            .with_fill(Fill::Fade)
+            .buffered(wave(0.0, 3.0)),
+        plot("hatch")
+            .line_fill()
+            .with_fill(Fill::Hatch)
+            .buffered(wave(2.0, 2.0)),
+        plot("crosshatch")
+            .histogram()
+            .with_fill(Fill::CrossHatch)
+            .buffered(wave(4.0, 1.0).step_by(4)),
+        plot("dots")
+            .line_fill_raw()
+            .with_fill(Fill::Dots)
+            .buffered(wave(1.0, 0.5))
+    ));
+
+    let w = util::create_test_file("fills.svg");
+
+    data.build_and_label(("fills", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn fills_bar() -> fmt::Result {
+    let data = [
+        (20, "potato"),
+        (14, "broccoli"),
+        (53, "pizza"),
+        (30, "avocado"),
+    ];
+
+    let (plots, yticks) = poloto::build::bar::gen_bar_with_fill("", data, [0], Fill::Hatch);
+
+    let w = util::create_test_file("fills_bar.svg");
+
+    poloto::data(plots)
+        .map_yticks(|_| yticks)
+        .build_and_label(("Comparison of Food Tastiness", "Tastiness", "Foods"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn fill_ids() -> fmt::Result {
+    let render = |id: &str| {
+        let data = poloto::data(poloto::plots!(
+            plot("fade")
+                .line_fill()
+                .with_fill(Fill::Fade)
+                .buffered([[0.0, 1.0], [1.0, 2.0]].into_iter()),
+            plot("hatch")
+                .histogram()
+                .with_fill(Fill::Hatch)
+                .buffered([[0.0, 2.0], [1.0, 1.0]].into_iter())
+        ));
+
+        data.build_and_label(("fill ids", "x", "y"))
+            .with_id(id)
+            .append_to(poloto::header().light_theme())
+            .render_string()
+    };
+
+    let a = render("first")?;
+    let b = render("second")?;
+
+    assert!(a.contains(r#"id="first_fade""#));
+    assert!(a.contains(r#"id="first_fade_gradient""#));
+    assert!(a.contains(r#"id="first_hatch1""#));
+    assert!(b.contains(r#"id="second_hatch1""#));
+    assert!(!a.contains(r#"id="poloto_hatch1""#));
+
+    //The shapes only get classes, which a style element points at the defs.
+    assert!(a.contains(".poloto_fade{mask:url(#first_fade)}"));
+    assert!(a.contains(".poloto1hatch{fill:url(#first_hatch1)}"));
+    assert_eq!(a.matches(".poloto1hatch{").count(), 1);
+    assert!(!a.contains("fill:url(#first_hatch1);"));
+    assert!(!a.contains(r#"mask="#));
+
+    util::create_test_file("fill_ids.svg").write_str(&(a + &b))
+}
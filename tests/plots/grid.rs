@@ -0,0 +1,119 @@
+use super::*;
+use poloto::build::plot;
+
+#[test]
+fn grid() -> fmt::Result {
+    let facet = |k: f64| {
+        let data: Vec<_> = (0..50)
+            .map(|i| {
+                let x = i as f64 / 5.0;
+                [x, (x * k).sin() * k]
+            })
+            .collect();
+        poloto::data(poloto::plots!(plot("").line().buffered(data.into_iter())))
+    };
+
+    let w = util::create_test_file("grid.svg");
+
+    let header = poloto::header()
+        .with_viewbox([1200.0, 800.0])
+        .with_dim([1200.0, 800.0]);
+
+    poloto::render::grid(2, 2)
+        .with_viewbox(header.get_viewbox())
+        .share_x()
+        .share_y()
+        .add(facet(0.5), ("k=0.5", "x", "y"))
+        .add(facet(1.0), ("k=1", "x", "y"))
+        .add(facet(1.5), ("k=1.5", "x", "y"))
+        .add(facet(2.0), ("k=2", "x", "y"))
+        .label("small multiples")
+        .append_to(header.light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn grid_unique_clip_ids() {
+    let grid = || {
+        poloto::render::grid(1, 2)
+            .add(
+                poloto::data(poloto::plots!(plot("a")
+                    .line()
+                    .buffered([[0.0, 1.0], [1.0, 2.0]].into_iter()))),
+                ("a", "x", "y"),
+            )
+            .add(
+                poloto::data(poloto::plots!(plot("b")
+                    .line()
+                    .buffered([[0.0, 2.0], [1.0, 1.0]].into_iter()))),
+                ("b", "x", "y"),
+            )
+    };
+
+    let a = grid().label("grid").headless().render_string().unwrap();
+    let b = grid().label("grid").headless().render_string().unwrap();
+
+    let ids = |s: &str| -> Vec<String> {
+        s.split("id=\"")
+            .skip(1)
+            .map(|a| a[..a.find('"').unwrap()].to_string())
+            .collect()
+    };
+    let (a, b) = (ids(&a), ids(&b));
+    assert_eq!(a.len(), 2);
+    assert!(a.iter().all(|id| !b.contains(id)));
+
+    let c = grid()
+        .with_id("dash")
+        .label("grid")
+        .headless()
+        .render_string()
+        .unwrap();
+    assert!(c.contains("id=\"dash_clip0\""));
+    assert!(c.contains("url(#dash_clip1)"));
+}
+
+#[test]
+fn grid_mixed_axes() -> fmt::Result {
+    use poloto::num::timestamp::UnixTime;
+
+    let days: Vec<(UnixTime, f64)> = (0..7)
+        .map(|i| (UnixTime(1_600_000_000 + i * 86_400), i as f64))
+        .collect();
+
+    let counts = [(0i128, 3.0), (1, 1.0), (2, 4.0)];
+
+    //Plots with different types of numbers on their x axes can go in the same grid.
+    let s = poloto::render::grid(1, 3)
+        .with_id("mixed")
+        .share_y()
+        .add(
+            poloto::data(poloto::plots!(plot("time")
+                .line()
+                .buffered(days.into_iter()))),
+            ("time", "day", "y"),
+        )
+        .add(
+            poloto::data(poloto::plots!(plot("count")
+                .scatter()
+                .buffered(counts.into_iter()))),
+            ("count", "n", "y"),
+        )
+        .add(
+            poloto::data(poloto::plots!(plot("wave")
+                .line()
+                .buffered([[0.0, 0.0], [1.0, 8.0]].into_iter()))),
+            ("wave", "x", "y"),
+        )
+        .label("mixed axes")
+        .append_to(poloto::header().light_theme())
+        .render_string()?;
+
+    assert_eq!(s.matches("poloto_grid_cell").count(), 3);
+    assert!(s.contains("id=\"mixed_clip2\""));
+
+    //Only the first column has its y tick labels drawn.
+    assert_eq!(s.matches(">y<").count(), 1);
+
+    util::create_test_file("grid_mixed_axes.svg").write_str(&s)
+}
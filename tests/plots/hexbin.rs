@@ -0,0 +1,56 @@
+use super::*;
+use poloto::build::plot;
+
+#[test]
+fn hexbin() -> fmt::Result {
+    // A cheap deterministic generator so the output is the same every run.
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut rand = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed >> 11) as f64 / (1u64 << 53) as f64
+    };
+
+    // Sum a few uniform samples to get something close to a normal distribution.
+    let points: Vec<_> = (0..100_000)
+        .map(|_| {
+            let x: f64 = (0..4).map(|_| rand()).sum::<f64>() - 2.0;
+            let y: f64 = (0..4).map(|_| rand()).sum::<f64>() - 2.0;
+            (x, x * 0.5 + y)
+        })
+        .collect();
+
+    let plots = poloto::plots!(plot("density").hexbin().size(12.0).buffered(points.iter()));
+
+    let w = util::create_test_file("hexbin.svg");
+
+    poloto::data(plots)
+        .build_and_label(("hexbin", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn hexbin_colors_and_clip() -> fmt::Result {
+    let points = [(0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (5.0, 5.0), (10.0, 10.0)];
+
+    let s = poloto::data(poloto::plots!(plot("density")
+        .hexbin()
+        .buffered(points.iter())))
+    .build_and_label(("hexbin", "x", "y"))
+    .with_id("density")
+    .append_to(poloto::header().light_theme())
+    .render_string()?;
+
+    //The hexagons at the corners of the data are clipped to the plot area.
+    assert!(s.contains(r#"id="density_clip""#));
+    assert!(s.contains(r#"clip-path="url(#density_clip)""#));
+
+    //The fullest hexagon is at the top of the viridis scale, the emptiest at the bottom.
+    assert_eq!(s.matches(r##"fill="#fde725""##).count(), 2);
+    assert_eq!(s.matches(r##"fill="#440154""##).count(), 3);
+    assert!(!s.contains("fill-opacity"));
+
+    util::create_test_file("hexbin_colors.svg").write_str(&s)
+}
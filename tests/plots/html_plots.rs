@@ -59,6 +59,58 @@ fn custom_colors_html() -> fmt::Result {
     )
 }
 
+#[test]
+fn css_variables_html() -> fmt::Result {
+    use poloto::render::theme::ThemeBuilder;
+
+    let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
+
+    let l1 = plot("cos").line().buffered(x.zip_output(|x| x.cos()));
+    let l2 = plot("sin").line().buffered(x.zip_output(|x| x.sin()));
+
+    let s = poloto::data(poloto::plots!(l1, l2)).build_and_label((
+        "Demo: re-theme with css variables",
+        "x",
+        "y",
+    ));
+
+    let css = ThemeBuilder::light()
+        .with_css_variables(true)
+        .with_dark_mode(ThemeBuilder::dark())
+        .css();
+
+    let graph = s.headless().render_string().unwrap();
+
+    let mut w = util::create_test_file("css_variables.html");
+
+    let (header_start, header_end) = poloto::header().to_string();
+    write!(
+        w,
+        r###"
+        <html>
+        <head>
+        <style>
+        {0}
+        .sunset {{
+            --poloto-bg: #2b1d3a;
+            --poloto-text: #ffd6a5;
+            --poloto-axis: #ffd6a5;
+            --poloto-color-0: #ff7b54;
+            --poloto-color-1: #ffb26b;
+        }}
+        </style>
+        </head>
+        <button type="button" style="font-size: 24px;" onclick="document.getElementById('test').classList.toggle('sunset');">Toggle Sunset</button>
+        <div id="test">
+        {1}
+        </div>
+        </html>
+        "###,
+        css,
+        format_args!("{}{}{}", header_start, graph, header_end)
+    )
+}
+
 #[test]
 fn hover_shadow() -> fmt::Result {
     const HEADER: &'static str = r###"
@@ -152,3 +204,87 @@ body {
         HEADER, s
     )
 }
+
+#[test]
+fn render_html() -> fmt::Result {
+    use poloto::render::html::HtmlOptions;
+
+    let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
+
+    let l1 = plot("cos").line().buffered(x.zip_output(|x| x.cos()));
+    let l2 = plot("sin").scatter().buffered(x.zip_output(|x| x.sin()));
+
+    let s = poloto::data(poloto::plots!(l1, l2))
+        .build_and_label(("Demo: hover, and click the legend", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_html_string(&HtmlOptions::new().with_title("<demo>").interactive())?;
+
+    assert!(s.starts_with("<!DOCTYPE html>\n<html>"));
+    assert!(s.trim_end().ends_with("</html>"));
+    assert!(s.contains("<title>&lt;demo&gt;</title>"));
+    assert!(s.contains("<svg"));
+    assert!(s.contains(".poloto0stroke{stroke:blue;}"));
+    assert!(s.contains("tooltips:true,crosshair:true,legend_toggle:true"));
+
+    let mut w = util::create_test_file("render_html.html");
+    w.write_str(&s)
+}
+
+#[test]
+fn render_html_no_script() -> fmt::Result {
+    use poloto::render::html::HtmlOptions;
+
+    let s = poloto::data(
+        plot("")
+            .line()
+            .buffered([[0.0, 0.0], [1.0, 1.0]].into_iter()),
+    )
+    .build_and_label(("", "x", "y"))
+    .append_to(poloto::header().dark_theme())
+    .render_html_string(&HtmlOptions::new())?;
+
+    assert!(s.contains("<svg"));
+    assert!(!s.contains("<script>"));
+    assert!(!s.contains("<title>"));
+    Ok(())
+}
+
+#[test]
+fn render_html_rotated_labels() -> fmt::Result {
+    use poloto::render::html::HtmlOptions;
+    use poloto::render::TickLabels;
+
+    let canvas = poloto::render::render_opt()
+        .with_xtick_labels(TickLabels::Rotate(-45.0))
+        .with_margins([100.0, 25.0, 150.0, 150.0])
+        .move_into();
+
+    let s = poloto::data(
+        plot("sales")
+            .line()
+            .buffered((0..12).map(|i| [i as f64, (i * i) as f64])),
+    )
+    .map_opt(|_| canvas)
+    .build_and_label(("sales", "month", "units"))
+    .append_to(poloto::header().light_theme())
+    .render_html_string(&HtmlOptions::new().interactive())?;
+
+    //Rotated x labels are anchored like y labels, so the script has to go by their class.
+    let xticks = util::elements(&s, "text", "poloto_xtick_labels");
+    let yticks = util::elements(&s, "text", "poloto_ytick_labels");
+    assert!(!xticks.is_empty());
+    assert!(!yticks.is_empty());
+    for x in xticks.iter() {
+        assert_eq!(x["text-anchor"], "end");
+        assert!(x["transform"].starts_with("rotate(-45,"));
+    }
+    for y in yticks.iter() {
+        assert_eq!(y["text-anchor"], "end");
+        assert!(!y.contains_key("transform"));
+    }
+    assert!(s.contains(r#"ticks("poloto_xtick_labels","x")"#));
+    assert!(s.contains(r#"ticks("poloto_ytick_labels","y")"#));
+
+    let mut w = util::create_test_file("render_html_rotated_labels.html");
+    w.write_str(&s)
+}
@@ -0,0 +1,65 @@
+use super::*;
+use poloto::build::plot;
+use poloto::render::inset::inset;
+
+#[test]
+fn inset_zoom() -> fmt::Result {
+    let signal: Vec<_> = (0..400)
+        .map(|i| {
+            let x = i as f64 / 40.0;
+            [x, x.sin() + 0.05 * (x * 30.0).sin()]
+        })
+        .collect();
+
+    let zoom = poloto::data(poloto::plots!(plot("")
+        .line()
+        .buffered(signal[60..80].iter())))
+    .build_and_label(("zoomed", "", ""));
+
+    let w = util::create_test_file("inset.svg");
+
+    poloto::data(poloto::plots!(plot("signal")
+        .line()
+        .buffered(signal.iter())))
+    .build_and_label(("signal with noise", "x", "y"))
+    .inset(
+        inset(zoom)
+            .at([380.0, 250.0, 260.0, 140.0])
+            .magnify([1.5, 2.0], [0.9, 1.1]),
+    )
+    .append_to(poloto::header().light_theme())
+    .render_fmt_write(w)
+}
+
+#[test]
+fn inset_unique_clip_ids() {
+    let small = || {
+        poloto::data(poloto::plots!(plot("")
+            .line()
+            .buffered([[0.0, 1.0], [1.0, 2.0]].into_iter())))
+        .build_and_label(("", "", ""))
+    };
+
+    // An inset that has an inset of its own.
+    let nested = small().inset(inset(small()));
+
+    let svg = poloto::data(poloto::plots!(plot("")
+        .line()
+        .buffered([[0.0, 1.0], [1.0, 2.0]].into_iter())))
+    .build_and_label(("", "", ""))
+    .inset(inset(nested))
+    .inset(inset(small()))
+    .inset(inset(small()).with_id("zoom"))
+    .headless()
+    .render_string()
+    .unwrap();
+
+    let ids: Vec<_> = svg
+        .split("id=\"")
+        .skip(1)
+        .map(|a| &a[..a.find('"').unwrap()])
+        .collect();
+    assert_eq!(ids.len(), 4);
+    assert!(ids.iter().enumerate().all(|(i, id)| !ids[..i].contains(id)));
+    assert!(ids.contains(&"zoom_clip"));
+}
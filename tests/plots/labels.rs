@@ -0,0 +1,33 @@
+use super::*;
+use poloto::build::plot;
+
+#[test]
+fn labels() -> fmt::Result {
+    let rainfall = [
+        [1, 81],
+        [2, 62],
+        [3, 58],
+        [4, 45],
+        [5, 40],
+        [6, 33],
+        [7, 30],
+        [8, 36],
+        [9, 44],
+        [10, 67],
+        [11, 79],
+        [12, 85],
+    ];
+
+    let labels = poloto::render::Labels::new("Rainfall\nVancouver", "month\n(2021)", "mm")
+        .with_subtitle("Monthly totals")
+        .with_caption("Source: example weather station\nMissing days were interpolated");
+
+    let w = util::create_test_file("labels.svg");
+
+    poloto::data(poloto::plots!(plot("rain")
+        .histogram()
+        .cloned(rainfall.iter())))
+    .build_and_label(labels)
+    .append_to(poloto::header().light_theme())
+    .render_fmt_write(w)
+}
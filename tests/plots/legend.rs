@@ -0,0 +1,120 @@
+use super::*;
+use poloto::build::{plot, PlotIteratorExt};
+use poloto::render::{Corner, Legend};
+
+fn trig(legend: Legend, name: &str) -> fmt::Result {
+    let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
+
+    let canvas = poloto::render::render_opt().with_legend(legend).move_into();
+
+    let plots = poloto::plots!(
+        plot("sin(x)")
+            .line()
+            .buffered(x.clone().map(|x| [x, x.sin()])),
+        plot("cos(x)")
+            .scatter()
+            .buffered(x.clone().map(|x| [x, x.cos()])),
+        plot("sin(x)*cos(x)")
+            .histogram()
+            .buffered(x.step_by(5).map(|x| [x, x.sin() * x.cos()]))
+    );
+
+    let w = util::create_test_file(name);
+
+    poloto::data(plots)
+        .map_opt(|_| canvas)
+        .build_and_label(("trig", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn legend_top() -> fmt::Result {
+    trig(Legend::Top(3), "legend_top.svg")
+}
+
+#[test]
+fn legend_bottom() -> fmt::Result {
+    trig(Legend::Bottom(3), "legend_bottom.svg")
+}
+
+#[test]
+fn legend_inside() -> fmt::Result {
+    trig(Legend::Inside(Corner::BottomRight), "legend_inside.svg")
+}
+
+#[test]
+fn legend_hidden() -> fmt::Result {
+    trig(Legend::Hidden, "legend_hidden.svg")
+}
+
+#[test]
+fn legend_top_rows() -> fmt::Result {
+    let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
+
+    let lines = |unnamed: bool| {
+        let named = poloto::plots!(
+            plot("x").line().buffered(x.clone().map(|x| [x, x])),
+            plot("2x").line().buffered(x.clone().map(|x| [x, 2.0 * x])),
+            plot("3x").line().buffered(x.clone().map(|x| [x, 3.0 * x])),
+            plot("4x").line().buffered(x.clone().map(|x| [x, 4.0 * x])),
+            plot("5x").line().buffered(x.clone().map(|x| [x, 5.0 * x]))
+        );
+        let extra: Vec<_> = if unnamed {
+            vec![
+                plot("").line().buffered(x.clone().map(|x| [x, 6.0 * x])),
+                plot("").line().buffered(x.clone().map(|x| [x, 7.0 * x])),
+            ]
+        } else {
+            vec![]
+        };
+
+        poloto::data(named.chain(poloto::build::plots_dyn(extra)))
+            .map_opt(|mut o| o.with_legend(Legend::Top(2)).move_into())
+            .build_and_label(("lines", "x", "y"))
+            .append_to(poloto::header().light_theme())
+            .render_string()
+    };
+
+    let s = lines(false)?;
+
+    let ys = |class: &str, tag: &str| -> Vec<f64> {
+        util::elements(&s, tag, class)
+            .iter()
+            .map(|a| a["y"].parse().unwrap())
+            .collect()
+    };
+
+    // Three rows of two columns, between the title and the top of the plot area.
+    let legend = ys("poloto_legend_text", "text");
+    assert_eq!(legend.len(), 5);
+    let mut rows = legend.clone();
+    rows.dedup();
+    assert_eq!(rows.len(), 3);
+
+    let title = ys("poloto_title", "text")[0];
+
+    // The y axis is drawn from the bottom of the plot area to the top.
+    let top = util::elements(&s, "path", "poloto_axis_lines")
+        .iter()
+        .flat_map(|a| {
+            a["d"]
+                .split_whitespace()
+                .filter_map(|n| n.parse::<f64>().ok())
+                .skip(1)
+                .step_by(2)
+                .collect::<Vec<_>>()
+        })
+        .fold(f64::INFINITY, f64::min);
+    assert!(rows.iter().all(|&y| y > title && y < top));
+
+    // Plots without a name don't reserve a legend slot or any extra room.
+    let t = lines(true)?;
+    let legend_unnamed: Vec<f64> = util::elements(&t, "text", "poloto_legend_text")
+        .iter()
+        .map(|a| a["y"].parse().unwrap())
+        .collect();
+    assert_eq!(legend, legend_unnamed);
+
+    util::create_test_file("legend_top_rows.svg").write_str(&s)
+}
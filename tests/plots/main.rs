@@ -1,9 +1,32 @@
+mod annotation;
+mod fills;
+mod grid;
+mod hexbin;
 mod html_plots;
+mod inset;
+mod labels;
+mod legend;
+mod margins;
+mod minor_ticks;
+#[cfg(feature = "png")]
+mod png;
+mod polar;
+mod quiver;
+mod radar;
+mod reference;
+mod regression;
+mod series_attrs;
 mod simple;
 mod steps;
+mod styles;
+mod term;
 mod test_chart;
+mod theme;
+mod tick_labels;
+mod tikz;
 mod timestamp;
 mod util;
+mod waterfall;
 
 use fmt::Write;
 use std::fmt;
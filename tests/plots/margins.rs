@@ -0,0 +1,47 @@
+use super::*;
+use poloto::build::plot;
+
+#[test]
+fn custom_margins() -> fmt::Result {
+    let x = (0..50).map(|x| (x as f64 / 50.0) * 10.0);
+
+    let canvas = poloto::render::render_opt()
+        .with_margins([60.0, 10.0, 60.0, 80.0])
+        .with_legend_width(200.0)
+        .with_tick_spacing([50.0, 30.0])
+        .move_into();
+
+    let plots = poloto::plots!(
+        plot("sin(x)")
+            .line()
+            .buffered(x.clone().map(|x| [x, x.sin()])),
+        plot("cos(x)").line().buffered(x.map(|x| [x, x.cos()]))
+    );
+
+    let w = util::create_test_file("custom_margins.svg");
+
+    poloto::data(plots)
+        .map_opt(|_| canvas)
+        .build_and_label(("trig", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn auto_margins() -> fmt::Result {
+    let x = (0..50).map(|x| x as f64);
+
+    let canvas = poloto::render::render_opt().with_auto_margins().move_into();
+
+    let plots = poloto::plots!(plot("population")
+        .line()
+        .buffered(x.map(|x| [x, 1_234_567.0 + 40_000.0 * x])));
+
+    let w = util::create_test_file("auto_margins.svg");
+
+    poloto::data(plots)
+        .map_opt(|_| canvas)
+        .build_and_label(("growth", "year", "people"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
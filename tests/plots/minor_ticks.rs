@@ -0,0 +1,85 @@
+use hypermelon::format_move;
+
+use super::*;
+
+use chrono::TimeZone;
+use poloto::build::plot;
+use poloto::num::timestamp::UnixTime;
+
+#[test]
+fn minor_ticks_float() -> fmt::Result {
+    let canvas = poloto::render::render_opt()
+        .with_minor_ticks([true, true])
+        .with_minor_tick_lines([true, true])
+        .with_tick_lines([true, true])
+        .move_into();
+
+    let data = poloto::data(poloto::plots!(plot("sin").line().buffered((0..200).map(
+        |i| {
+            let x = i as f64 / 20.0;
+            [x, x.sin() * 3.0]
+        }
+    ))))
+    .map_opt(|_| canvas);
+
+    let w = util::create_test_file("minor_ticks_float.svg");
+
+    data.build_and_label(("minor ticks", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn minor_ticks_months() -> fmt::Result {
+    let timezone = &chrono::Utc;
+
+    let data: Vec<(UnixTime, f64)> = (1..=6)
+        .map(|m| (timezone.ymd(2021, m, 1).into(), (m * m) as f64))
+        .collect();
+
+    let canvas = poloto::render::render_opt()
+        .with_minor_ticks([true, true])
+        .with_minor_tick_lines([true, false])
+        .move_into();
+
+    let data =
+        poloto::data(poloto::plots!(plot("growth").line().cloned(data.iter()))).map_opt(|_| canvas);
+
+    let w = util::create_test_file("minor_ticks_months.svg");
+
+    data.build_and_label(("minor ticks", "month", "value"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn minor_ticks_custom() -> fmt::Result {
+    let canvas = poloto::render::render_opt()
+        .with_minor_ticks([true, false])
+        .with_minor_tick_lines([true, false])
+        .move_into();
+
+    // Quarters with a minor tick for every month.
+    let xticks = poloto::ticks::TickDistribution {
+        iter: (0..=12).step_by(3),
+        fmt: poloto::ticks::tick_fmt::DefaultTickFmt,
+        res: poloto::ticks::TickRes { dash_size: None },
+    }
+    .with_minor_ticks((0..=12).filter(|m| m % 3 != 0))
+    .with_tick_fmt(|&q| format_move!("Q{}", q / 3 + 1));
+
+    let data = poloto::data(poloto::plots!(plot("sales")
+        .line()
+        .buffered((0..=12).map(|m| (m as i128, (m * m) as f64)))))
+    .map_opt(|_| canvas)
+    .map_xticks(|_| xticks);
+
+    let s = data
+        .build_and_label(("minor ticks", "month", "sales"))
+        .append_to(poloto::header().light_theme())
+        .render_string()?;
+
+    assert_eq!(s.matches("poloto_minor_tick_line\"").count(), 8);
+
+    util::create_test_file("minor_ticks_custom.svg").write_str(&s)
+}
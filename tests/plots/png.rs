@@ -0,0 +1,54 @@
+use poloto::build::plot;
+
+fn data() -> impl poloto::build::PlotIterator<X = f64, Y = f64> {
+    let wave = |phase: f64| (0..30).map(move |i| [i as f64, (i as f64 / 5.0 + phase).sin()]);
+
+    poloto::plots!(
+        plot("sin").line().buffered(wave(0.0)),
+        plot("cos").line_fill().buffered(wave(1.5))
+    )
+}
+
+#[test]
+fn png_render() {
+    std::fs::create_dir_all("target/assets").unwrap();
+
+    poloto::data(data())
+        .build_and_label(("png", "x", "y"))
+        .append_to(poloto::header().dark_theme())
+        .render_png("target/assets/png_render.png", 1.0)
+        .unwrap();
+
+    let bytes = std::fs::read("target/assets/png_render.png").unwrap();
+    assert_eq!(&bytes[1..4], b"PNG");
+}
+
+#[test]
+fn png_scale() {
+    let bytes = poloto::data(data())
+        .build_and_label(("png", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_png_bytes(2.0)
+        .unwrap();
+
+    //The width and height are the first fields of the IHDR chunk.
+    let width = u32::from_be_bytes(bytes[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(bytes[20..24].try_into().unwrap());
+    assert_eq!([width, height], [1600, 1000]);
+}
+
+#[test]
+fn png_io_error() {
+    let err = poloto::data(data())
+        .build_and_label(("png", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_png("target/assets/missing_dir/png_io_error.png", 1.0)
+        .unwrap_err();
+
+    match err {
+        poloto::render::png::PngError::Io(e) => {
+            assert_eq!(e.kind(), std::io::ErrorKind::NotFound)
+        }
+        e => panic!("expected an io error, got {}", e),
+    }
+}
@@ -0,0 +1,43 @@
+use hypermelon::format_move;
+
+use super::*;
+use poloto::build::plot;
+
+#[test]
+fn polar() -> fmt::Result {
+    // https://en.wikipedia.org/wiki/Rose_(mathematics)
+    let theta = poloto::util::range_iter([0.0, 360.0], 400);
+    let rose = theta.zip_output(|t: f64| (3.0 * t.to_radians()).cos().abs());
+    let circle = theta.zip_output(|_| 0.5);
+
+    let wind = [
+        [0.0, 0.3],
+        [45.0, 0.5],
+        [90.0, 0.9],
+        [135.0, 0.4],
+        [180.0, 0.2],
+        [225.0, 0.6],
+        [270.0, 0.7],
+        [315.0, 0.35],
+        [360.0, 0.3],
+    ];
+
+    let plots = poloto::plots!(
+        plot("rose").line().buffered(rose),
+        plot("circle").line_fill().buffered(circle),
+        plot("wind").scatter().cloned(wind.iter()),
+        poloto::build::markers([], [0.0])
+    );
+
+    let xticks = poloto::ticks::from_iter((0..360).step_by(45).map(|x| x as f64))
+        .with_tick_fmt(|&v| format_move!("{}°", v));
+
+    let w = util::create_test_file("polar.svg");
+
+    poloto::data(plots)
+        .polar([0.0, 360.0])
+        .map_xticks(|_| xticks)
+        .build_and_label(("polar", "angle", "radius"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
@@ -0,0 +1,67 @@
+use super::*;
+use poloto::build::plot;
+
+#[test]
+fn quiver() -> fmt::Result {
+    let grid = |n: i32| (-n..=n).map(move |a| a as f64 / n as f64 * 2.0);
+
+    // A vortex around the origin.
+    let vortex = grid(6)
+        .flat_map(|x| grid(6).map(move |y| [x, y, -y, x]))
+        .collect::<Vec<_>>();
+
+    let plots = poloto::plots!(
+        plot("vortex").quiver().buffered(vortex),
+        plot("wind")
+            .quiver()
+            .scale(0.5)
+            .buffered([[-2.0, -3.0, 1.0, 0.5], [0.0, -3.0, 1.0, 0.8]])
+    );
+
+    let w = util::create_test_file("quiver.svg");
+
+    poloto::data(plots)
+        .build_and_label(("vector field", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn quiver_marker_ids() -> fmt::Result {
+    let arrows = |y: f64| [[0.0, y, 1.0, 0.5], [1.0, y, 1.0, 0.8]];
+
+    let plot = || {
+        poloto::data(poloto::plots!(
+            plot("a").quiver().buffered(arrows(0.0)),
+            plot("b").quiver().buffered(arrows(1.0))
+        ))
+        .map_opt(|mut o| {
+            o.num_css_class(Some(1));
+            o
+        })
+        .build_and_label(("arrows", "x", "y"))
+    };
+
+    //Both series have the same color, so they share a marker.
+    let a = plot().with_id("field").headless().render_string()?;
+    assert_eq!(a.matches("<marker").count(), 1);
+    assert!(a.contains(r#"id="field_arrow0""#));
+    assert_eq!(a.matches("url(#field_arrow0)").count(), 4);
+
+    //Two renders in the same document get different ids.
+    let b = plot().headless().render_string()?;
+    let c = plot().headless().render_string()?;
+    let id = |s: &str| {
+        let s = &s[s.find("<marker").unwrap()..];
+        s.split("id=\"")
+            .nth(1)
+            .unwrap()
+            .split('"')
+            .next()
+            .unwrap()
+            .to_string()
+    };
+    assert_ne!(id(&b), id(&c));
+
+    util::create_test_file("quiver_marker_ids.svg").write_str(&a)
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn radar() -> fmt::Result {
+    let r = poloto::build::radar::axes([
+        ("binary size (MB)", [0.0, 20.0]),
+        ("runtime (s)", [0.0, 5.0]),
+        ("memory (GB)", [0.0, 4.0]),
+        ("compile time (min)", [0.0, 10.0]),
+        ("startup (ms)", [0.0, 500.0]),
+    ])
+    .line_fill("debug", [16.0, 4.2, 3.1, 2.0, 350.0])
+    .line_fill("release", [6.5, 1.1, 1.8, 6.0, 120.0])
+    .line("release-lto", [5.0, 0.9, 1.7, 9.0, 100.0]);
+
+    let w = util::create_test_file("radar.svg");
+
+    r.label("Build profiles")
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+#[should_panic]
+fn radar_empty_range() {
+    poloto::build::radar::axes([("a", [0.0, 1.0]), ("b", [2.0, 2.0]), ("c", [0.0, 1.0])]);
+}
+
+#[test]
+fn radar_clamped() -> fmt::Result {
+    let s = poloto::build::radar::axes([("a", [0.0, 1.0]), ("b", [0.0, 1.0]), ("c", [0.0, 1.0])])
+        .line("out of range", [-5.0, 0.5, 50.0])
+        .label("clamped")
+        .append_to(poloto::header().light_theme())
+        .render_string()?;
+
+    assert!(!s.contains("NaN") && !s.contains("inf"));
+
+    let mut w = util::create_test_file("radar_clamped.svg");
+    w.write_str(&s)
+}
@@ -0,0 +1,28 @@
+use super::*;
+use poloto::build::{self, plot};
+
+#[test]
+fn reference() -> fmt::Result {
+    let latency = (0..50).map(|i| {
+        let i = i as f64;
+        (
+            i,
+            40.0 + 15.0 * (i / 4.0).sin() + if i > 30.0 { 20.0 } else { 0.0 },
+        )
+    });
+
+    let plots = poloto::plots!(
+        plot("maintenance").vband([10.0, 14.0]),
+        build::hband([0.0, 20.0]),
+        plot("latency").line().buffered(latency),
+        plot("SLO").hline(80.0),
+        plot("deploy").vline(30.0)
+    );
+
+    let w = util::create_test_file("reference.svg");
+
+    poloto::data(plots)
+        .build_and_label(("latency", "time", "ms"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
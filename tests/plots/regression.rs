@@ -0,0 +1,79 @@
+use super::*;
+use poloto::build::plot;
+use poloto::build::regression::Model;
+
+#[test]
+fn regression() -> fmt::Result {
+    // Benchmark time in ms over a series of commits.
+    let history = [
+        (0.0, 12.1),
+        (1.0, 12.9),
+        (2.0, 13.2),
+        (3.0, 14.8),
+        (4.0, 15.1),
+        (5.0, 17.3),
+        (6.0, 18.0),
+        (7.0, 20.9),
+        (8.0, 22.4),
+        (9.0, 25.8),
+    ];
+
+    let linear = Model::Linear.fit(history).unwrap();
+    assert!(linear.r_squared() > 0.9);
+    assert!(linear.coefficients()[1] > 1.0);
+
+    let plots = poloto::plots!(
+        plot("history").scatter().buffered(history.iter()),
+        plot("linear")
+            .trend(Model::Linear)
+            .with_equation()
+            .buffered(history),
+        plot("quadratic")
+            .trend(Model::Polynomial(2))
+            .with_equation()
+            .buffered(history),
+        plot("exponential")
+            .trend(Model::Exponential)
+            .with_equation()
+            .buffered(history)
+    );
+
+    let w = util::create_test_file("regression.svg");
+
+    poloto::data(plots)
+        .build_and_label(("benchmark history", "commit", "time in ms"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn regression_small_x() {
+    // Reaction rate against concentration in mol/L.
+    let points: Vec<_> = (0..10)
+        .map(|i| {
+            let x = 1e-4 + i as f64 * 1e-5;
+            (x, 2.0 + 3e4 * x - 5e7 * x * x)
+        })
+        .collect();
+
+    let fit = Model::Polynomial(2).fit(points.iter().copied()).unwrap();
+    let c = fit.coefficients();
+    assert!((c[0] - 2.0).abs() < 1e-6);
+    assert!((c[1] - 3e4).abs() / 3e4 < 1e-6);
+    assert!((c[2] + 5e7).abs() / 5e7 < 1e-6);
+    assert!(fit.r_squared() > 0.999_999);
+
+    let linear = Model::Linear.fit(points.iter().copied()).unwrap();
+    assert!(linear.coefficients()[1] > 0.0);
+
+    // Large x values are scaled before fitting.
+    let large = Model::Linear
+        .fit([(1e200, 1.0), (2e200, 2.0), (3e200, 3.0)])
+        .unwrap();
+    assert!((large.eval(4e200) - 4.0).abs() < 1e-9);
+
+    // Sums that overflow don't panic.
+    assert!(Model::Linear
+        .fit([(0.0, f64::MAX), (1.0, f64::MAX), (2.0, f64::MAX)])
+        .is_none());
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+use poloto::build::plot;
+use poloto::build::style::Fill;
+
+#[test]
+fn series_attrs() -> fmt::Result {
+    let wave = |phase: f64| (0..30).map(move |i| [i as f64, (i as f64 / 5.0 + phase).sin()]);
+
+    let data = poloto::data(poloto::plots!(
+        plot("highlighted")
+            .with_class("highlight")
+            .with_id("main_series")
+            .with_style("stroke-width:4")
+            .with_attr("data-series", "main")
+            .line()
+            .buffered(wave(0.0)),
+        plot("faded")
+            .with_class("faded")
+            .with_class("background")
+            .line_fill()
+            .with_fill(Fill::Hatch)
+            .buffered(wave(1.0)),
+        plot("points")
+            .with_id("points")
+            .scatter()
+            .buffered(wave(2.0))
+    ));
+
+    let mut s = String::new();
+    data.build_and_label(("series attributes", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(&mut s)?;
+
+    assert!(s.contains("poloto0stroke poloto0dash highlight"));
+    assert!(s.contains(r#"id="main_series""#));
+    assert!(s.contains(r#"id="main_series_legend""#));
+    assert!(s.contains(r#"style="stroke-width:4""#));
+    assert!(s.contains(r#"data-series="main""#));
+    assert!(s.contains("poloto1fill poloto_hatch poloto1hatch faded background"));
+    assert!(s.contains(r#"id="points""#));
+
+    let mut w = util::create_test_file("series_attrs.svg");
+    use std::fmt::Write;
+    w.write_str(&s)
+}
+
+#[test]
+fn series_attrs_quiver_hexbin_waterfall() -> fmt::Result {
+    let data = poloto::data(poloto::plots!(
+        plot("wind")
+            .with_class("arrows")
+            .with_id("wind")
+            .quiver()
+            .buffered([[0.0, 0.0, 1.0, 0.5], [1.0, 0.0, 1.0, 0.8]]),
+        plot("density")
+            .with_class("bins")
+            .with_id("density")
+            .with_attr("data-series", "density")
+            .hexbin()
+            .buffered([(0.0, 0.0), (0.1, 0.1), (1.0, 1.0)].into_iter())
+    ));
+
+    let s = data
+        .build_and_label(("series attributes", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_string()?;
+
+    assert!(s.contains("poloto_quiver poloto0stroke arrows"));
+    assert!(s.contains(r#"id="wind""#));
+    assert!(s.contains(r#"id="wind_legend""#));
+    assert!(s.contains("poloto_hexbin bins"));
+    assert!(s.contains(r#"id="density""#));
+    assert!(s.contains(r#"id="density_legend""#));
+    assert!(s.contains(r#"data-series="density""#));
+
+    let waterfall = poloto::build::waterfall::Waterfall::new()
+        .delta("baseline", 120.0)
+        .delta("strip symbols", -40.0)
+        .total("total")
+        .with_connectors()
+        .with_class("steps")
+        .with_id("size")
+        .gen_simple()
+        .label(("waterfall", "size", "step"))
+        .append_to(poloto::header().light_theme())
+        .render_string()?;
+
+    assert!(waterfall.contains("poloto_waterfall poloto_waterfall_increase poloto0fill steps"));
+    assert!(waterfall.contains("poloto_waterfall_connector steps"));
+    assert!(waterfall.contains(r#"id="size_increase""#));
+    assert!(waterfall.contains(r#"id="size_decrease_legend""#));
+    assert!(waterfall.contains(r#"id="size_connector""#));
+
+    use std::fmt::Write;
+    util::create_test_file("series_attrs_other.svg").write_str(&s)
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+use poloto::build::plot;
+use poloto::build::style::{Dash, MarkerShape};
+use poloto::render::theme::ThemeBuilder;
+use poloto::render::Theme;
+
+#[test]
+fn styles_chosen() -> fmt::Result {
+    let wave = |phase: f64| (0..30).map(move |i| [i as f64, (i as f64 / 5.0 + phase).sin()]);
+
+    let data = poloto::data(poloto::plots!(
+        plot("solid").line().buffered(wave(0.0)),
+        plot("dashed")
+            .line()
+            .with_dash(Dash::Dashed)
+            .with_width(3.0)
+            .buffered(wave(1.0)),
+        plot("dotted")
+            .line()
+            .with_dash(Dash::Dotted)
+            .with_marker(MarkerShape::Square)
+            .buffered(wave(2.0)),
+        plot("triangles")
+            .scatter()
+            .with_marker(MarkerShape::Triangle)
+            .with_width(10.0)
+            .buffered(wave(3.0))
+    ));
+
+    let w = util::create_test_file("styles_chosen.svg");
+
+    data.build_and_label(("chosen styles", "x", "y"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn styles_auto() -> fmt::Result {
+    let css = ThemeBuilder::light()
+        .with_palette(["black", "dimgray", "gray", "darkgray"])
+        .with_dashes(Dash::ALL)
+        .css();
+
+    let canvas = poloto::render::render_opt()
+        .num_css_class(Some(4))
+        .with_auto_markers(true)
+        .move_into();
+
+    let lines = (0..4).map(|i| {
+        plot(format!("line {}", i))
+            .line()
+            .buffered((0..20).map(move |x| [x as f64, (x * (i + 1)) as f64]))
+    });
+
+    let points = (0..4).map(|i| {
+        plot(format!("points {}", i))
+            .scatter()
+            .buffered((0..20).map(move |x| [x as f64, (x * (i + 1)) as f64 + 10.0]))
+    });
+
+    let data = poloto::data(poloto::build::plots_dyn(lines.chain(points))).map_opt(|_| canvas);
+
+    let w = util::create_test_file("styles_auto.svg");
+
+    data.build_and_label(("grayscale", "x", "y"))
+        .append_to(poloto::header().custom_theme(Theme::new(&css)))
+        .render_fmt_write(w)
+}
@@ -0,0 +1,84 @@
+use super::*;
+use poloto::build::plot;
+use poloto::render::term::{Charset, TermOptions};
+
+fn data() -> impl poloto::build::PlotIterator<X = f64, Y = f64> {
+    let wave = |phase: f64| (0..50).map(move |i| [i as f64, (i as f64 / 8.0 + phase).sin()]);
+
+    poloto::plots!(
+        plot("sin").line().buffered(wave(0.0)),
+        plot("cos").scatter().buffered(wave(1.5)),
+        plot("a note").text()
+    )
+}
+
+#[test]
+fn term_braille() -> std::fmt::Result {
+    let s = poloto::data(data())
+        .build_and_label(("waves", "x", "y"))
+        .render_term_string(&TermOptions::new().with_color(false))?;
+
+    util::create_test_file("term_braille.txt").write_str(&s)?;
+
+    assert!(s.contains("waves"));
+    assert!(s.contains("● sin"));
+    assert!(s.contains("● cos"));
+    assert!(s.contains("a note"));
+    assert!(!s.contains('\x1b'));
+    assert!(s.chars().any(|c| ('\u{2801}'..='\u{28ff}').contains(&c)));
+
+    //The rows of the plot area all have the same width.
+    let rows: Vec<_> = s
+        .lines()
+        .filter(|l| l.contains('┤') || l.contains('│'))
+        .collect();
+    assert_eq!(rows.len(), 16);
+    assert!(rows
+        .iter()
+        .all(|l| l.chars().count() == rows[0].chars().count()));
+
+    //The y tick labels sit on the axis and the x tick labels below it.
+    assert!(rows.iter().any(|l| l.trim_start().starts_with("0.5┤")));
+    assert!(rows.iter().any(|l| l.trim_start().starts_with("-0.5┤")));
+    let xlabels = s
+        .lines()
+        .find(|l| l.trim_start().starts_with("0 "))
+        .unwrap();
+    assert!(xlabels
+        .split_whitespace()
+        .eq(["0", "5", "10", "15", "20", "25", "30", "35", "40", "45"]));
+    Ok(())
+}
+
+#[test]
+fn term_block_color() -> std::fmt::Result {
+    let s = poloto::data(data())
+        .build_and_label(("waves", "x", "y"))
+        .render_term_string(
+            &TermOptions::new()
+                .with_size([40, 10])
+                .with_charset(Charset::Block),
+        )?;
+
+    util::create_test_file("term_block.txt").write_str(&s)?;
+
+    assert!(s.contains("\x1b[34m"));
+    assert!(s.contains("\x1b[31m"));
+    assert!(s.chars().any(|c| "▀▄█".contains(c)));
+    assert!(!s.chars().any(|c| ('\u{2801}'..='\u{28ff}').contains(&c)));
+
+    let rows: Vec<_> = s
+        .lines()
+        .filter(|l| l.contains('┤') || l.contains('│'))
+        .collect();
+    assert_eq!(rows.len(), 10);
+    assert!(rows.iter().any(|l| l.trim_start().starts_with("0.0┤")));
+    let xlabels = s
+        .lines()
+        .find(|l| l.trim_start().starts_with("0 "))
+        .unwrap();
+    assert!(xlabels
+        .split_whitespace()
+        .eq(["0", "5", "10", "15", "20", "25", "30", "35", "40", "45"]));
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+use poloto::build::plot;
+use poloto::render::theme::ThemeBuilder;
+use poloto::render::Theme;
+
+#[test]
+fn theme_presets() {
+    assert_eq!(ThemeBuilder::light().css(), Theme::light().get_str());
+    assert_eq!(ThemeBuilder::dark().css(), Theme::dark().get_str());
+}
+
+#[test]
+fn theme_custom() -> fmt::Result {
+    let palette = [
+        "#e6194b", "#3cb44b", "#ffe119", "#4363d8", "#f58231", "#911eb4", "#46f0f0", "#f032e6",
+        "#bcf60c", "#fabebe",
+    ];
+
+    let theme = ThemeBuilder::dark()
+        .with_background("black")
+        .with_font_family("monospace")
+        .with_title_font_size(30)
+        .with_line_width(3.0)
+        .with_palette(palette);
+    let css = theme.css();
+
+    let canvas = theme.render_opt();
+
+    let plots = (0..palette.len()).map(|i| {
+        plot(format!("{}", i)).line().buffered(
+            (0..50).map(move |x| [x as f64, (x as f64 / 8.0 + i as f64).sin() + i as f64]),
+        )
+    });
+
+    let data = poloto::data(poloto::build::plots_dyn(plots)).map_opt(|_| canvas);
+
+    let w = util::create_test_file("theme_custom.svg");
+
+    data.build_and_label(("custom theme", "x", "y"))
+        .append_to(poloto::header().custom_theme(Theme::new(&css)))
+        .render_fmt_write(w)
+}
+
+#[test]
+fn theme_palette() -> fmt::Result {
+    use poloto::render::theme::Palette;
+
+    let viridis = Palette::Viridis.sample(12);
+    assert_eq!(viridis.len(), 12);
+    assert_eq!(viridis[0], "#440154");
+    assert_eq!(viridis[11], "#fde725");
+    assert_eq!(Palette::OkabeIto.sample(10).len(), 10);
+
+    let theme = ThemeBuilder::light().with_palette(viridis);
+    let css = theme.css();
+    assert!(css.contains(".poloto11fill{fill:#fde725;}"));
+    assert!(!css.contains(".poloto12fill"));
+
+    let canvas = theme.render_opt();
+
+    // One more plot than colors, so the last one wraps around to the first color.
+    let plots = (0..13).map(|i| {
+        plot(format!("{}", i))
+            .line()
+            .buffered((0..50).map(move |x| [x as f64, x as f64 * (i + 1) as f64]))
+    });
+
+    let data = poloto::data(poloto::build::plots_dyn(plots)).map_opt(|_| canvas);
+
+    let s = data
+        .build_and_label(("viridis", "x", "y"))
+        .append_to(poloto::header().custom_theme(Theme::new(&css)))
+        .render_string()?;
+
+    assert!(s.contains("poloto11stroke"));
+    assert!(!s.contains("poloto12stroke"));
+
+    util::create_test_file("theme_palette.svg").write_str(&s)
+}
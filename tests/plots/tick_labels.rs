@@ -0,0 +1,62 @@
+use hypermelon::format_move;
+
+use super::*;
+use poloto::build::plot;
+use poloto::render::TickLabels;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+fn months(labels: TickLabels, margins: [f64; 4], name: &str) -> fmt::Result {
+    let sales = [31, 28, 35, 40, 52, 61, 70, 66, 48, 41, 37, 45];
+
+    let canvas = poloto::render::render_opt()
+        .with_xtick_labels(labels)
+        .with_margins(margins)
+        .move_into();
+
+    let xticks = poloto::ticks::TickDistribution::new(0..12)
+        .with_tick_fmt(|&v| format_move!("{}", MONTHS[v as usize]));
+
+    let data = poloto::data(poloto::plots!(plot("sales")
+        .line()
+        .buffered(sales.iter().enumerate().map(|(i, &s)| (i as i128, s)))))
+    .map_opt(|_| canvas)
+    .map_xticks(|_| xticks);
+
+    let w = util::create_test_file(name);
+
+    data.build_and_label(("sales", "month", "units"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn tick_labels_rotate() -> fmt::Result {
+    months(
+        TickLabels::Rotate(-45.0),
+        [100.0, 25.0, 150.0, 150.0],
+        "tick_labels_rotate.svg",
+    )
+}
+
+#[test]
+fn tick_labels_auto() -> fmt::Result {
+    months(
+        TickLabels::Auto,
+        [100.0, 25.0, 100.0, 150.0],
+        "tick_labels_auto.svg",
+    )
+}
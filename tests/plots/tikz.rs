@@ -0,0 +1,108 @@
+use super::*;
+
+use poloto::build::plot;
+use poloto::build::style::Dash;
+use poloto::render::tikz::TikzOptions;
+
+#[test]
+fn tikz() -> std::fmt::Result {
+    let wave = |phase: f64| (0..30).map(move |i| [i as f64, (i as f64 / 5.0 + phase).sin()]);
+
+    let data = poloto::data(poloto::plots!(
+        plot("$\\sin(x)$")
+            .line()
+            .with_dash(Dash::Dashed)
+            .buffered(wave(0.0)),
+        plot("samples").scatter().buffered(wave(1.0)),
+        plot("").line_fill().buffered(wave(2.0)),
+        plot("a note").text()
+    ));
+
+    let s = data
+        .build_and_label(("waves\nof data", "time", "value"))
+        .render_tikz_string(&TikzOptions::new().with_width("\\linewidth"))?;
+
+    util::create_test_file("tikz.tex").write_str(&s)?;
+
+    assert!(s.starts_with("\\begin{tikzpicture}\n\\begin{axis}["));
+    assert!(s.ends_with("\\end{axis}\n\\end{tikzpicture}\n"));
+    assert!(s.contains("title={waves\\\\of data}"));
+    assert!(s.contains("width={\\linewidth}"));
+    assert!(s.contains("xticklabels={{0},{5},{10},{15},{20},{25}}"));
+    assert!(s.contains("\\addplot+[mark=none, dashed] coordinates"));
+    assert!(s.contains("\\addlegendentry{$\\sin(x)$}"));
+    assert!(s.contains("only marks"));
+    assert!(s.contains("forget plot"));
+    assert!(s.contains("\\closedcycle;"));
+    assert!(s.contains("\\addlegendimage{empty legend}\n\\addlegendentry{a note}"));
+    assert_eq!(s.matches("\\addplot").count(), 3);
+    Ok(())
+}
+
+#[test]
+fn tikz_no_picture() -> std::fmt::Result {
+    let data = poloto::data(
+        plot("")
+            .line()
+            .buffered([[0.0, 0.0], [1.0, f64::NAN], [2.0, 1.0]].into_iter()),
+    );
+
+    let s = data
+        .build_and_label(("", "", ""))
+        .render_tikz_string(&TikzOptions::new().with_picture(false))?;
+
+    assert!(s.starts_with("\\begin{axis}["));
+    assert!(!s.contains("tikzpicture"));
+    assert!(!s.contains("legend pos"));
+    assert!(s.contains("(0.5,nan)"));
+    Ok(())
+}
+
+#[test]
+fn tikz_quiver_hexbin_waterfall() -> std::fmt::Result {
+    let data = poloto::data(poloto::plots!(
+        plot("wind")
+            .quiver()
+            .buffered([[0.0, 0.0, 1.0, 0.5], [1.0, 0.0, 0.0, 0.0]]),
+        plot("density")
+            .hexbin()
+            .buffered([(0.0, 0.0), (0.001, 0.001), (1.0, 1.0)].into_iter())
+    ));
+
+    let s = data
+        .build_and_label(("vectors", "x", "y"))
+        .render_tikz_string(&TikzOptions::new())?;
+
+    util::create_test_file("tikz_quiver_hexbin.tex").write_str(&s)?;
+
+    //The zero length arrow is left out.
+    assert!(s.contains(
+        "\\addplot+[quiver={u=\\thisrow{u},v=\\thisrow{v}}, -stealth, mark=none] table {\nx y u v\n0 0 1 0.5\n};"
+    ));
+    assert!(s.contains(
+        "scatter, only marks, mark=*, point meta=explicit, colormap/viridis] coordinates"
+    ));
+    assert!(s.contains(") [2]"));
+    assert!(s.contains(") [1]"));
+    assert!(!s.contains(") [3]"));
+
+    let s = poloto::build::waterfall::Waterfall::new()
+        .delta("baseline", 120.0)
+        .delta("strip symbols", -40.0)
+        .total("total")
+        .with_connectors()
+        .gen_simple()
+        .label(("waterfall", "size", "step"))
+        .render_tikz_string(&TikzOptions::new())?;
+
+    util::create_test_file("tikz_waterfall.tex").write_str(&s)?;
+
+    //The bars are rectangles half as tall as the space between two steps.
+    assert_eq!(s.matches("mark=none, fill, area legend").count(), 3);
+    assert!(!s.contains("line width"));
+    assert!(s.contains("(0,0.1875) (1,0.1875) (1,0.3125) (0,0.3125) (0,0.1875) (nan,nan)"));
+    assert!(s.contains("mark=none, black, forget plot"));
+    assert!(!s.contains("only marks"));
+    assert!(s.contains("(nan,nan)"));
+    Ok(())
+}
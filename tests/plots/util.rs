@@ -3,3 +3,36 @@ pub fn create_test_file(filename: &str) -> hypermelon::tools::Adaptor<std::fs::F
     let file = std::fs::File::create(format!("target/assets/{}", filename)).unwrap();
     hypermelon::tools::upgrade_write(file)
 }
+
+///
+/// The attributes of every `tag` element in the svg that has `class` in its list of classes.
+///
+pub fn elements(
+    svg: &str,
+    tag: &str,
+    class: &str,
+) -> Vec<std::collections::HashMap<String, String>> {
+    svg.split('<')
+        .filter_map(|a| a.strip_prefix(tag))
+        .filter(|a| a.starts_with(char::is_whitespace))
+        .map(|a| {
+            //Attribute names and values alternate when split on quotes.
+            let head = &a[..a.find('>').unwrap()];
+            let parts: Vec<_> = head.split('"').collect();
+            parts
+                .chunks_exact(2)
+                .map(|p| {
+                    (
+                        p[0].trim().trim_end_matches('=').to_string(),
+                        p[1].to_string(),
+                    )
+                })
+                .collect::<std::collections::HashMap<_, _>>()
+        })
+        .filter(|attrs| {
+            attrs
+                .get("class")
+                .is_some_and(|c| c.split_whitespace().any(|c| c == class))
+        })
+        .collect()
+}
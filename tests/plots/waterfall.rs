@@ -0,0 +1,41 @@
+use super::*;
+use poloto::build::waterfall::Waterfall;
+
+#[test]
+fn waterfall() -> fmt::Result {
+    let data = Waterfall::new()
+        .delta("baseline", 412.0)
+        .delta("serde", 96.0)
+        .delta("regex", 58.0)
+        .delta("lto", -130.0)
+        .delta("strip symbols", -74.0)
+        .total("release")
+        .with_connectors()
+        .gen_simple();
+
+    let w = util::create_test_file("waterfall.svg");
+
+    data.label(("binary size", "size in kb", "step"))
+        .append_to(poloto::header().light_theme())
+        .render_fmt_write(w)
+}
+
+#[test]
+fn waterfall_part_colors() -> fmt::Result {
+    //There are no increases, but the decreases still get their own color.
+    let s = Waterfall::new()
+        .delta("lto", -130.0)
+        .delta("strip symbols", -74.0)
+        .total("release")
+        .gen_simple()
+        .label(("binary size", "size in kb", "step"))
+        .append_to(poloto::header().light_theme())
+        .render_string()?;
+
+    assert!(s.contains("poloto_waterfall poloto_waterfall_decrease poloto1fill"));
+    assert!(s.contains("poloto_waterfall poloto_waterfall_total poloto2fill"));
+    assert!(!s.contains("poloto_waterfall_increase poloto_legend_icon"));
+    assert_eq!(s.matches("poloto_text poloto_legend_text").count(), 2);
+
+    util::create_test_file("waterfall_part_colors.svg").write_str(&s)
+}